@@ -71,6 +71,7 @@ fn vec_publisher_delegate_publish()
 }
 
 #[test]
+#[allow(clippy::similar_names)]
 fn handling_publisher_eq()
 {
     #[derive(PartialEq)]
@@ -120,7 +121,7 @@ fn format()
     let hp = HandlingPublisher::from(DummyPub {});
 
     assert_eq!(
-        format!("{:?}", hp),
+        format!("{hp:?}"),
         "HandlingPublisher { delegate: DummyPub }".to_string()
     );
 }
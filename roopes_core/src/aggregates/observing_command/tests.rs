@@ -69,6 +69,7 @@ fn notify_observable_command()
 }
 
 #[test]
+#[allow(clippy::similar_names)]
 fn observable_detach_hash_command()
 {
     let has_run_a = Rc::new(RefCell::new(false));
@@ -113,6 +114,7 @@ fn observable_detach_hash_command()
 }
 
 #[test]
+#[allow(clippy::similar_names)]
 fn observable_detach_vec_command()
 {
     let run_ct_a = Rc::new(RefCell::new(0));
@@ -18,7 +18,10 @@
 
 use crate::prelude::{
     handler::lambda::Delegate,
-    publisher_subscriber::heap,
+    publisher_subscriber::{
+        heap,
+        Propagation,
+    },
     *,
 };
 use delegate::delegate;
@@ -87,6 +90,21 @@ where
     }
 }
 
+/// Never consumes the messages it receives.
+impl<H, M> ConsumingSubscriber<M> for SubscribingHandler<H, M>
+where
+    H: Handler<M>,
+{
+    fn consume(
+        &self,
+        message: &M,
+    ) -> Propagation
+    {
+        self.handler.handle(message);
+        Propagation::Continue
+    }
+}
+
 #[allow(clippy::inline_always)]
 impl<H, M> Handler<M> for SubscribingHandler<H, M>
 where
@@ -4,7 +4,6 @@
 //! this module.
 
 #![feature(trait_alias)]
-#![feature(associated_type_bounds)]
 #![allow(unused_imports)]
 #![deny(
     clippy::pedantic,
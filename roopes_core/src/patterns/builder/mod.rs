@@ -0,0 +1,71 @@
+//! Supports the Builder pattern, in which an
+//! object is configured one part at a time before
+//! it is constructed.  Builders are typically
+//! generated by `derive(Builder)`, which
+//! implements [`Buildable`] for the target, and
+//! converts a builder whose required parts are
+//! all set into the target with [`From`], so
+//! one builder can configure another's part
+//! through a closure.
+
+/// A type which is constructed through a
+/// builder.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+///
+/// struct Point
+/// {
+///     x: i32,
+/// }
+///
+/// #[derive(Default)]
+/// struct PointBuilder
+/// {
+///     x: i32,
+/// }
+///
+/// impl From<PointBuilder> for Point
+/// {
+///     fn from(builder: PointBuilder) -> Point
+///     {
+///         Point { x: builder.x }
+///     }
+/// }
+///
+/// impl Buildable for Point
+/// {
+///     type Builder = PointBuilder;
+///
+///     fn builder() -> PointBuilder
+///     {
+///         PointBuilder::default()
+///     }
+/// }
+///
+/// fn configure<T, B>(configure: impl FnOnce(T::Builder) -> B) -> T
+/// where
+///     T: Buildable,
+///     B: Into<T>,
+/// {
+///     configure(T::builder()).into()
+/// }
+///
+/// let point: Point = configure(|b| PointBuilder { x: 2, ..b });
+/// assert_eq!(2, point.x);
+/// ```
+pub trait Buildable
+{
+    /// The builder initially given for the type.
+    type Builder;
+
+    /// Gives a new builder, with no parts set.
+    fn builder() -> Self::Builder;
+}
+
+/// Exposes [`Buildable`] at the library level.
+pub mod prelude
+{
+    pub use super::Buildable;
+}
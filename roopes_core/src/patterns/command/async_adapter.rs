@@ -0,0 +1,76 @@
+//! Provides an [`AsyncCommand`] which wraps a
+//! synchronous [`Command`].
+
+use super::{
+    AsyncCommand,
+    Command,
+};
+use crate::primitives::asynchronous::BoxFuture;
+
+/// Adapts a [`Command`] into an
+/// [`AsyncCommand`].  The delegate is executed
+/// when the returned future is first polled, and
+/// the future completes immediately afterwards.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::{
+///     prelude::*,
+///     primitives::asynchronous::executor::block_on,
+/// };
+/// use std::{
+///     cell::Cell,
+///     rc::Rc,
+/// };
+///
+/// let has_run = Rc::new(Cell::new(false));
+/// let has_run_ext = has_run.clone();
+/// let command: command::AsyncAdapter<_> = command::Heap::from(move || {
+///     has_run_ext.set(true);
+/// })
+/// .into();
+///
+/// let future = command.execute_async();
+/// assert!(!has_run.get());
+///
+/// block_on(future);
+/// assert!(has_run.get());
+/// ```
+pub struct AsyncAdapter<C>
+where
+    C: Command,
+{
+    delegate: C,
+}
+
+impl<C> AsyncAdapter<C>
+where
+    C: Command,
+{
+    /// Creates an [`AsyncAdapter`] from a given
+    /// [`Command`].
+    pub fn new(delegate: C) -> AsyncAdapter<C>
+    {
+        AsyncAdapter { delegate }
+    }
+}
+
+impl<C> AsyncCommand for AsyncAdapter<C>
+where
+    C: Command,
+{
+    fn execute_async(&self) -> BoxFuture<'_, ()>
+    {
+        Box::pin(async move { self.delegate.execute() })
+    }
+}
+
+impl<C> From<C> for AsyncAdapter<C>
+where
+    C: Command,
+{
+    fn from(delegate: C) -> Self
+    {
+        AsyncAdapter::new(delegate)
+    }
+}
@@ -0,0 +1,42 @@
+//! Provides a heap-based implementation of
+//! [`AsyncCommand`].
+
+use super::AsyncCommand;
+use crate::primitives::asynchronous::BoxFuture;
+use delegate::delegate;
+
+/// Stores a delegate [`AsyncCommand`] in a
+/// [`Box`] for later use.
+pub struct AsyncHeap
+{
+    delegate: Box<dyn AsyncCommand>,
+}
+
+impl AsyncHeap
+{
+    /// Creates a new [`AsyncHeap`] with the
+    /// supplied delegate.
+    #[must_use]
+    pub fn new(delegate: Box<dyn AsyncCommand>) -> AsyncHeap
+    {
+        AsyncHeap { delegate }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl AsyncCommand for AsyncHeap
+{
+    delegate! {
+        to self.delegate {
+           fn execute_async(&self) -> BoxFuture<'_, ()>;
+        }
+    }
+}
+
+impl From<Box<dyn AsyncCommand>> for AsyncHeap
+{
+    fn from(delegate: Box<dyn AsyncCommand>) -> Self
+    {
+        AsyncHeap { delegate }
+    }
+}
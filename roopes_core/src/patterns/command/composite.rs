@@ -0,0 +1,245 @@
+//! Provides [`Composite`], a macro [`Command`]
+//! which executes a group of [`Command`]s as
+//! one.
+
+use crate::{
+    prelude::*,
+    primitives,
+};
+
+/// Executes each of its delegate [`Command`]s in
+/// order.  When the delegates are
+/// [`UndoableCommand`]s the [`Composite`] is
+/// too, undoing them in reverse order.  When they
+/// are [`TryCommand`]s, execution stops at the
+/// first failure; see [`Composite::transactional`]
+/// to also roll back the delegates which had
+/// already succeeded.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let macro_command: command::Composite<command::Heap> = ["a", "b", "c"]
+///     .into_iter()
+///     .map(|name| {
+///         let log = log.clone();
+///         move || log.borrow_mut().push(name)
+///     })
+///     .collect();
+///
+/// macro_command.execute();
+/// assert_eq!(vec!["a", "b", "c"], *log.borrow());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Composite<C>
+{
+    commands: Vec<C>,
+}
+
+impl<C> Composite<C>
+{
+    /// Creates a [`Composite`] which executes the
+    /// given commands in order.
+    #[must_use]
+    pub fn new(commands: Vec<C>) -> Composite<C>
+    {
+        Composite { commands }
+    }
+
+    /// Adds a command to be executed after those
+    /// already present.
+    pub fn push(
+        &mut self,
+        command: C,
+    )
+    {
+        self.commands.push(command);
+    }
+
+    /// Gives the number of delegate commands.
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        self.commands.len()
+    }
+
+    /// Indicates whether there are no delegate
+    /// commands.
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.commands.is_empty()
+    }
+
+    /// Converts this [`Composite`] into a
+    /// [`Transaction`], which rolls back the
+    /// delegates that succeeded when a later one
+    /// fails.
+    #[must_use]
+    pub fn transactional(self) -> Transaction<C>
+    where
+        C: TryCommand + UndoableCommand,
+    {
+        Transaction { composite: self }
+    }
+}
+
+impl<C> Default for Composite<C>
+{
+    fn default() -> Self
+    {
+        Composite::new(Vec::new())
+    }
+}
+
+impl<C> Command for Composite<C>
+where
+    C: Command,
+{
+    fn execute(&self)
+    {
+        for command in &self.commands {
+            command.execute();
+        }
+    }
+}
+
+impl<C> UndoableCommand for Composite<C>
+where
+    C: UndoableCommand,
+{
+    fn undo(&self)
+    {
+        for command in self.commands.iter().rev() {
+            command.undo();
+        }
+    }
+}
+
+impl<C> TryCommand for Composite<C>
+where
+    C: TryCommand,
+{
+    type Error = C::Error;
+
+    fn try_execute(&self) -> Result<(), C::Error>
+    {
+        for command in &self.commands {
+            command.try_execute()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> From<Vec<C>> for Composite<C>
+{
+    fn from(commands: Vec<C>) -> Self
+    {
+        Composite::new(commands)
+    }
+}
+
+impl<C> FromIterator<C> for Composite<C>
+{
+    fn from_iter<T: IntoIterator<Item = C>>(iter: T) -> Self
+    {
+        Composite::new(iter.into_iter().collect())
+    }
+}
+
+impl<D> FromIterator<D> for Composite<super::Heap>
+where
+    D: primitives::executable::lambda::Delegate + 'static,
+{
+    fn from_iter<T: IntoIterator<Item = D>>(iter: T) -> Self
+    {
+        iter.into_iter().map(super::Heap::from).collect()
+    }
+}
+
+impl<C> Extend<C> for Composite<C>
+{
+    fn extend<T: IntoIterator<Item = C>>(
+        &mut self,
+        iter: T,
+    )
+    {
+        self.commands.extend(iter);
+    }
+}
+
+/// A [`Composite`] whose
+/// [`TryCommand::try_execute`] is all-or-nothing:
+/// when a delegate fails, the delegates which had
+/// already succeeded are undone in reverse order
+/// before the error is returned.  Created by
+/// [`Composite::transactional`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Transaction<C>
+where
+    C: TryCommand + UndoableCommand,
+{
+    composite: Composite<C>,
+}
+
+impl<C> Transaction<C>
+where
+    C: TryCommand + UndoableCommand,
+{
+    /// Gives back the underlying [`Composite`].
+    #[must_use]
+    pub fn into_inner(self) -> Composite<C>
+    {
+        self.composite
+    }
+}
+
+impl<C> TryCommand for Transaction<C>
+where
+    C: TryCommand + UndoableCommand,
+{
+    type Error = C::Error;
+
+    fn try_execute(&self) -> Result<(), C::Error>
+    {
+        let commands = &self.composite.commands;
+
+        for (i, command) in commands.iter().enumerate() {
+            if let Err(error) = command.try_execute() {
+                for succeeded in commands[..i].iter().rev() {
+                    succeeded.undo();
+                }
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> Command for Transaction<C>
+where
+    C: TryCommand + UndoableCommand,
+{
+    fn execute(&self)
+    {
+        self.composite.execute();
+    }
+}
+
+impl<C> UndoableCommand for Transaction<C>
+where
+    C: TryCommand + UndoableCommand,
+{
+    fn undo(&self)
+    {
+        self.composite.undo();
+    }
+}
@@ -81,3 +81,14 @@ where
         self.command.execute();
     }
 }
+
+impl<D, H> UndoableCommand for Hashable<D, H>
+where
+    D: UndoableCommand,
+    H: Hash + Eq,
+{
+    fn undo(&self)
+    {
+        self.command.undo();
+    }
+}
@@ -0,0 +1,272 @@
+//! Provides [`CommandHistory`], which records
+//! executed [`UndoableCommand`]s so they can be
+//! undone and redone.
+
+use crate::prelude::*;
+use std::collections::VecDeque;
+
+/// Executes [`UndoableCommand`]s, recording them
+/// on an undo stack.  Undone commands move to a
+/// redo stack, which is discarded as soon as a
+/// new command is executed.
+///
+/// Consecutive commands are coalesced with
+/// [`UndoableCommand::merge`] unless
+/// [`CommandHistory::seal`] was called in between
+/// or the history is at its savepoint.  When a
+/// depth limit is set, the oldest commands are
+/// forgotten once the undo stack grows past it.
+///
+/// A savepoint marks the position at which the
+/// history is "clean", e.g.: when the document
+/// was last saved.  A new history starts clean.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::Cell,
+///     rc::Rc,
+/// };
+///
+/// let value = Rc::new(Cell::new(0));
+/// let mut history = command::CommandHistory::new();
+///
+/// let add = |amount: i32| {
+///     let (up, down) = (value.clone(), value.clone());
+///     command::UndoableHeap::from(command::UndoableLambda::new(
+///         move || up.set(up.get() + amount),
+///         move || down.set(down.get() - amount),
+///     ))
+/// };
+///
+/// history.execute(add(1));
+/// history.execute(add(2));
+/// assert_eq!(3, value.get());
+/// assert!(!history.is_clean());
+///
+/// history.undo();
+/// assert_eq!(1, value.get());
+/// history.redo();
+/// assert_eq!(3, value.get());
+/// ```
+pub struct CommandHistory<C>
+where
+    C: UndoableCommand,
+{
+    undo_stack: VecDeque<C>,
+    redo_stack: Vec<C>,
+    depth_limit: Option<usize>,
+    savepoint: Option<usize>,
+    sealed: bool,
+}
+
+impl<C> CommandHistory<C>
+where
+    C: UndoableCommand,
+{
+    /// Creates an empty, clean [`CommandHistory`]
+    /// with no depth limit.
+    #[must_use]
+    pub fn new() -> CommandHistory<C>
+    {
+        CommandHistory {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            depth_limit: None,
+            savepoint: Some(0),
+            sealed: false,
+        }
+    }
+
+    /// Creates an empty, clean [`CommandHistory`]
+    /// which remembers at most `depth_limit`
+    /// commands for undoing.
+    #[must_use]
+    pub fn with_depth_limit(depth_limit: usize) -> CommandHistory<C>
+    {
+        CommandHistory {
+            depth_limit: Some(depth_limit),
+            ..CommandHistory::new()
+        }
+    }
+
+    /// Gives the maximum number of commands
+    /// which can be undone, if limited.
+    #[must_use]
+    pub fn depth_limit(&self) -> Option<usize>
+    {
+        self.depth_limit
+    }
+
+    /// Changes the depth limit, immediately
+    /// forgetting the oldest commands if the undo
+    /// stack is now too deep.
+    pub fn set_depth_limit(
+        &mut self,
+        depth_limit: Option<usize>,
+    )
+    {
+        self.depth_limit = depth_limit;
+        self.trim();
+    }
+
+    /// Executes the given command and records it,
+    /// discarding any undone commands.  The
+    /// command is merged into the most recent one
+    /// where possible.
+    pub fn execute(
+        &mut self,
+        command: C,
+    )
+    {
+        command.execute();
+
+        if self.savepoint > Some(self.undo_stack.len()) {
+            self.savepoint = None;
+        }
+        self.redo_stack.clear();
+
+        let merged = !self.sealed
+            && !self.is_clean()
+            && self
+                .undo_stack
+                .back_mut()
+                .is_some_and(|last| last.merge(&command));
+
+        if !merged {
+            self.undo_stack.push_back(command);
+            self.trim();
+        }
+
+        self.sealed = false;
+    }
+
+    /// Undoes the most recent command, moving it
+    /// onto the redo stack.  Returns `false` if
+    /// there was nothing to undo.
+    pub fn undo(&mut self) -> bool
+    {
+        let Some(command) = self.undo_stack.pop_back() else {
+            return false;
+        };
+
+        command.undo();
+        self.redo_stack.push(command);
+        self.sealed = true;
+
+        true
+    }
+
+    /// Re-executes the most recently undone
+    /// command, moving it back onto the undo
+    /// stack.  Returns `false` if there was
+    /// nothing to redo.
+    pub fn redo(&mut self) -> bool
+    {
+        let Some(command) = self.redo_stack.pop() else {
+            return false;
+        };
+
+        command.execute();
+        self.undo_stack.push_back(command);
+        self.sealed = true;
+        self.trim();
+
+        true
+    }
+
+    /// Prevents the next executed command from
+    /// merging into the most recent one, e.g.:
+    /// after a pause in typing.
+    pub fn seal(&mut self)
+    {
+        self.sealed = true;
+    }
+
+    /// Marks the current position as clean.
+    pub fn set_savepoint(&mut self)
+    {
+        self.savepoint = Some(self.undo_stack.len());
+        self.sealed = true;
+    }
+
+    /// Indicates whether undoing and redoing has
+    /// returned the history to its savepoint.
+    /// Once the savepoint is discarded, e.g.:
+    /// forgotten due to the depth limit, the
+    /// history is never clean again until a new
+    /// savepoint is set.
+    #[must_use]
+    pub fn is_clean(&self) -> bool
+    {
+        self.savepoint == Some(self.undo_stack.len())
+    }
+
+    /// Indicates whether [`CommandHistory::undo`]
+    /// would do anything.
+    #[must_use]
+    pub fn can_undo(&self) -> bool
+    {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Indicates whether [`CommandHistory::redo`]
+    /// would do anything.
+    #[must_use]
+    pub fn can_redo(&self) -> bool
+    {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Gives the number of commands which can be
+    /// undone.
+    #[must_use]
+    pub fn undo_len(&self) -> usize
+    {
+        self.undo_stack.len()
+    }
+
+    /// Gives the number of commands which can be
+    /// redone.
+    #[must_use]
+    pub fn redo_len(&self) -> usize
+    {
+        self.redo_stack.len()
+    }
+
+    /// Forgets every recorded command without
+    /// undoing them.  The current position
+    /// becomes the savepoint.
+    pub fn clear(&mut self)
+    {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.savepoint = Some(0);
+        self.sealed = false;
+    }
+
+    fn trim(&mut self)
+    {
+        let Some(limit) = self.depth_limit else {
+            return;
+        };
+
+        while self.undo_stack.len() > limit {
+            self.undo_stack.pop_front();
+            self.savepoint = self
+                .savepoint
+                .and_then(|savepoint| savepoint.checked_sub(1));
+        }
+    }
+}
+
+impl<C> Default for CommandHistory<C>
+where
+    C: UndoableCommand,
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
@@ -16,11 +16,31 @@
 //!
 //! ![command diagram][command-diagram]
 
+pub mod async_adapter;
+pub mod async_heap;
+pub mod composite;
 pub mod hashable;
 pub mod heap;
+pub mod history;
+pub mod sync_heap;
+pub mod try_heap;
+pub mod undoable_heap;
+pub mod undoable_lambda;
 
+use crate::primitives::asynchronous::BoxFuture;
+pub use async_adapter::AsyncAdapter;
+pub use async_heap::AsyncHeap;
+pub use composite::Composite;
 pub use hashable::Hashable;
 pub use heap::Heap;
+pub use history::CommandHistory;
+pub use sync_heap::SyncHeap;
+pub use try_heap::TryHeap;
+pub use undoable_heap::UndoableHeap;
+pub use undoable_lambda::UndoableLambda;
+
+#[cfg(test)]
+mod tests;
 
 /// Encapsulates a repeatedly callable block of
 /// code.
@@ -30,9 +50,70 @@ pub trait Command
     fn execute(&self);
 }
 
-/// Exposes the [`Command`] type at the library
-/// level.
+/// A [`Command`] whose effects can be reverted.
+/// Typically recorded by a [`CommandHistory`]
+/// so it can later be undone and redone.
+pub trait UndoableCommand: Command
+{
+    /// Reverts the effects of the most recent
+    /// [`Command::execute`].
+    fn undo(&self);
+
+    /// Attempts to absorb the effects of `next`,
+    /// which has just been executed, into this
+    /// command so that a single
+    /// [`UndoableCommand::undo`] reverts both.
+    /// Returns `true` if `next` was absorbed and
+    /// may be discarded.  Defaults to never
+    /// merging.
+    fn merge(
+        &mut self,
+        next: &Self,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = next;
+        false
+    }
+}
+
+/// The fallible counterpart of [`Command`].
+/// Encapsulates a repeatedly callable block of
+/// code which may fail.
+pub trait TryCommand
+{
+    /// The error produced when execution fails.
+    type Error;
+
+    /// Calls the encapsulated block of code.
+    ///
+    /// # Errors
+    /// [`TryCommand::Error`]: The block of code
+    /// failed.
+    fn try_execute(&self) -> Result<(), Self::Error>;
+}
+
+/// The asynchronous counterpart of [`Command`].
+/// Encapsulates a repeatedly callable block of
+/// code which completes at some later point.
+pub trait AsyncCommand
+{
+    /// Calls the encapsulated block of code,
+    /// giving back a future which completes when
+    /// the block does.
+    fn execute_async(&self) -> BoxFuture<'_, ()>;
+}
+
+/// Exposes the [`Command`], [`UndoableCommand`],
+/// [`TryCommand`] and [`AsyncCommand`] types at
+/// the library level.
 pub mod prelude
 {
-    pub use super::Command;
+    pub use super::{
+        AsyncCommand,
+        Command,
+        TryCommand,
+        UndoableCommand,
+    };
 }
@@ -0,0 +1,82 @@
+//! Provides a thread-safe, heap-based
+//! implementation of [`Command`].
+
+use crate::{
+    prelude::*,
+    primitives,
+};
+use delegate::delegate;
+use std::sync::Arc;
+
+/// Stores a delegate [`Command`] in an [`Arc`]
+/// for later use.  Unlike [`super::Heap`],
+/// [`SyncHeap`] is [`Send`] and [`Sync`], and
+/// cloning it shares the delegate.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// let command = command::SyncHeap::from(|| {
+///     println!("Hello world!");
+/// });
+/// let worker_command = command.clone();
+/// std::thread::spawn(move || worker_command.execute())
+///     .join()
+///     .unwrap();
+/// command.execute();
+/// ```
+#[derive(Clone)]
+pub struct SyncHeap
+{
+    delegate: Arc<dyn Command + Send + Sync>,
+}
+
+impl SyncHeap
+{
+    /// Creates a new [`SyncHeap`] with the
+    /// supplied delegate.
+    #[must_use]
+    pub fn new(delegate: Arc<dyn Command + Send + Sync>) -> SyncHeap
+    {
+        SyncHeap { delegate }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl Command for SyncHeap
+{
+    delegate! {
+        to self.delegate {
+           fn execute(&self);
+        }
+    }
+}
+
+impl From<SyncHeap> for Arc<dyn Command + Send + Sync>
+{
+    fn from(value: SyncHeap) -> Self
+    {
+        value.delegate
+    }
+}
+
+impl From<Arc<dyn Command + Send + Sync>> for SyncHeap
+{
+    fn from(delegate: Arc<dyn Command + Send + Sync>) -> Self
+    {
+        SyncHeap { delegate }
+    }
+}
+
+impl<D> From<D> for SyncHeap
+where
+    D: primitives::executable::lambda::Delegate + Send + Sync + 'static,
+{
+    fn from(delegate: D) -> Self
+    {
+        let delegate =
+            Arc::new(CommandExecutable::new(executable::Lambda::new(delegate)));
+
+        SyncHeap { delegate }
+    }
+}
@@ -1,8 +1,15 @@
-use command::Command;
-use roopes::prelude::*;
+use crate::prelude::*;
 use std::{
     cell::RefCell,
     rc::Rc,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+    thread,
 };
 
 #[test]
@@ -11,7 +18,7 @@ fn simple_lambda_command_refcell_mutation()
     let has_run = Rc::new(RefCell::new(false));
     let has_run_ext = has_run.clone();
 
-    let lc = command::Lambda::new(move || {
+    let lc = command::Heap::from(move || {
         (*has_run_ext.borrow_mut()) = true;
     });
     lc.execute();
@@ -25,7 +32,7 @@ fn simple_hashable_command_refcell_mutation()
     let has_run = Rc::new(RefCell::new(false));
     let has_run_ext = has_run.clone();
 
-    let lc = command::Lambda::new(move || {
+    let lc = command::Heap::from(move || {
         (*has_run_ext.borrow_mut()) = true;
     });
 
@@ -35,3 +42,353 @@ fn simple_hashable_command_refcell_mutation()
 
     assert!((*has_run.borrow()));
 }
+
+#[test]
+fn sync_heap_command_across_threads()
+{
+    let run_ct = Arc::new(AtomicUsize::new(0));
+    let run_ct_ext = run_ct.clone();
+
+    let command = command::SyncHeap::from(move || {
+        run_ct_ext.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let command = command.clone();
+            thread::spawn(move || command.execute())
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(4, run_ct.load(Ordering::SeqCst));
+}
+
+#[test]
+fn async_adapter_and_heap_execute()
+{
+    use crate::primitives::asynchronous::executor::block_on;
+
+    let has_run = Rc::new(RefCell::new(false));
+    let has_run_ext = has_run.clone();
+
+    let adapter: command::AsyncAdapter<_> = command::Heap::from(move || {
+        (*has_run_ext.borrow_mut()) = true;
+    })
+    .into();
+    let heap = command::AsyncHeap::new(Box::new(adapter));
+
+    let future = heap.execute_async();
+
+    assert!(!(*has_run.borrow()));
+    block_on(future);
+    assert!((*has_run.borrow()));
+}
+
+#[test]
+fn try_heap_from_closure()
+{
+    let count = Rc::new(RefCell::new(0));
+    let count_ext = count.clone();
+
+    let command = command::TryHeap::from(move || {
+        let mut count = count_ext.borrow_mut();
+        *count += 1;
+        if *count > 2 {
+            Err(*count)
+        } else {
+            Ok(())
+        }
+    });
+
+    assert_eq!(Ok(()), command.try_execute());
+    assert_eq!(Ok(()), command.try_execute());
+    assert_eq!(Err(3), command.try_execute());
+}
+
+struct Append
+{
+    text: Rc<RefCell<String>>,
+    suffix: RefCell<String>,
+}
+
+impl Append
+{
+    fn new(
+        text: &Rc<RefCell<String>>,
+        suffix: &str,
+    ) -> Append
+    {
+        Append {
+            text: text.clone(),
+            suffix: RefCell::new(suffix.to_string()),
+        }
+    }
+}
+
+impl Command for Append
+{
+    fn execute(&self)
+    {
+        self.text.borrow_mut().push_str(&self.suffix.borrow());
+    }
+}
+
+impl UndoableCommand for Append
+{
+    fn undo(&self)
+    {
+        let mut text = self.text.borrow_mut();
+        let len = text.len() - self.suffix.borrow().len();
+        text.truncate(len);
+    }
+
+    fn merge(
+        &mut self,
+        next: &Self,
+    ) -> bool
+    {
+        self.suffix.borrow_mut().push_str(&next.suffix.borrow());
+        true
+    }
+}
+
+#[test]
+fn history_undo_redo()
+{
+    let text = Rc::new(RefCell::new(String::new()));
+    let mut history = command::CommandHistory::new();
+
+    history.execute(Append::new(&text, "a"));
+    history.seal();
+    history.execute(Append::new(&text, "b"));
+    assert_eq!("ab", *text.borrow());
+
+    assert!(history.undo());
+    assert_eq!("a", *text.borrow());
+    assert!(history.can_redo());
+
+    assert!(history.redo());
+    assert_eq!("ab", *text.borrow());
+
+    assert!(history.undo());
+    assert!(history.undo());
+    assert!(!history.undo());
+    assert_eq!("", *text.borrow());
+
+    history.execute(Append::new(&text, "c"));
+    assert!(!history.can_redo());
+    assert_eq!("c", *text.borrow());
+}
+
+#[test]
+fn history_merges_consecutive_commands()
+{
+    let text = Rc::new(RefCell::new(String::new()));
+    let mut history = command::CommandHistory::new();
+
+    history.execute(Append::new(&text, "h"));
+    history.execute(Append::new(&text, "i"));
+    history.seal();
+    history.execute(Append::new(&text, "!"));
+    assert_eq!(2, history.undo_len());
+    assert_eq!("hi!", *text.borrow());
+
+    history.undo();
+    assert_eq!("hi", *text.borrow());
+    history.undo();
+    assert_eq!("", *text.borrow());
+}
+
+#[test]
+fn history_depth_limit()
+{
+    let text = Rc::new(RefCell::new(String::new()));
+    let mut history = command::CommandHistory::with_depth_limit(2);
+
+    for suffix in ["a", "b", "c"] {
+        history.execute(Append::new(&text, suffix));
+        history.seal();
+    }
+    assert_eq!(2, history.undo_len());
+
+    while history.undo() {}
+    assert_eq!("a", *text.borrow());
+    assert!(!history.is_clean());
+}
+
+#[test]
+fn history_savepoint_tracking()
+{
+    let text = Rc::new(RefCell::new(String::new()));
+    let mut history = command::CommandHistory::new();
+    assert!(history.is_clean());
+
+    history.execute(Append::new(&text, "a"));
+    history.set_savepoint();
+    assert!(history.is_clean());
+
+    // Merging into the saved command would make
+    // the savepoint lie, so it is not merged.
+    history.execute(Append::new(&text, "b"));
+    assert_eq!(2, history.undo_len());
+    assert!(!history.is_clean());
+
+    history.undo();
+    assert!(history.is_clean());
+    history.undo();
+    assert!(!history.is_clean());
+    history.redo();
+    assert!(history.is_clean());
+
+    history.undo();
+    history.execute(Append::new(&text, "z"));
+    assert!(!history.is_clean());
+    history.undo();
+    assert!(!history.is_clean());
+}
+
+#[test]
+fn history_of_undoable_heaps()
+{
+    let value = Rc::new(RefCell::new(0));
+    let mut history = command::CommandHistory::new();
+
+    for amount in [1, 10] {
+        let up = value.clone();
+        let down = value.clone();
+        history.execute(command::UndoableHeap::from(
+            command::UndoableLambda::new(
+                move || *up.borrow_mut() += amount,
+                move || *down.borrow_mut() -= amount,
+            ),
+        ));
+    }
+
+    assert_eq!(2, history.undo_len());
+    assert_eq!(11, *value.borrow());
+    history.undo();
+    assert_eq!(1, *value.borrow());
+}
+
+#[test]
+fn composite_executes_in_order()
+{
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let composite: command::Composite<command::Heap> = (0..3)
+        .map(|i| {
+            let log = log.clone();
+            move || log.borrow_mut().push(i)
+        })
+        .collect();
+    composite.execute();
+
+    assert_eq!(3, composite.len());
+    assert_eq!(vec![0, 1, 2], *log.borrow());
+}
+
+#[test]
+fn composite_of_hashables()
+{
+    let count = Rc::new(RefCell::new(0));
+
+    let composite: command::Composite<_> = ["a", "b"]
+        .into_iter()
+        .map(|id| {
+            let count = count.clone();
+            command::Hashable::new(
+                command::Heap::from(move || *count.borrow_mut() += 1),
+                id,
+            )
+        })
+        .collect();
+    composite.execute();
+
+    assert_eq!(2, *count.borrow());
+}
+
+#[test]
+fn composite_undoes_in_reverse()
+{
+    let text = Rc::new(RefCell::new(String::new()));
+    let mut composite = command::Composite::default();
+    composite.extend([Append::new(&text, "ab"), Append::new(&text, "c")]);
+
+    composite.execute();
+    assert_eq!("abc", *text.borrow());
+    composite.undo();
+    assert_eq!("", *text.borrow());
+}
+
+struct Step
+{
+    log: Rc<RefCell<Vec<String>>>,
+    name: &'static str,
+    fails: bool,
+}
+
+impl Command for Step
+{
+    fn execute(&self)
+    {
+        self.log.borrow_mut().push(format!("do {}", self.name));
+    }
+}
+
+impl TryCommand for Step
+{
+    type Error = &'static str;
+
+    fn try_execute(&self) -> Result<(), Self::Error>
+    {
+        if self.fails {
+            return Err(self.name);
+        }
+        self.execute();
+        Ok(())
+    }
+}
+
+impl UndoableCommand for Step
+{
+    fn undo(&self)
+    {
+        self.log.borrow_mut().push(format!("undo {}", self.name));
+    }
+}
+
+fn steps(log: &Rc<RefCell<Vec<String>>>) -> command::Composite<Step>
+{
+    [("a", false), ("b", false), ("c", true), ("d", false)]
+        .into_iter()
+        .map(|(name, fails)| {
+            Step {
+                log: log.clone(),
+                name,
+                fails,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn composite_try_execute_stops_without_rollback()
+{
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    assert_eq!(Err("c"), steps(&log).try_execute());
+    assert_eq!(vec!["do a", "do b"], *log.borrow());
+}
+
+#[test]
+fn composite_transaction_rolls_back()
+{
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    assert_eq!(Err("c"), steps(&log).transactional().try_execute());
+    assert_eq!(vec!["do a", "do b", "undo b", "undo a"], *log.borrow());
+}
@@ -0,0 +1,77 @@
+//! Provides a heap-based implementation of
+//! [`TryCommand`].
+
+use super::TryCommand;
+use delegate::delegate;
+
+/// Stores a delegate [`TryCommand`] in a [`Box`]
+/// for later use.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// let command = command::TryHeap::from(|| Err::<(), _>("failed"));
+/// assert_eq!(Err("failed"), command.try_execute());
+/// ```
+pub struct TryHeap<E>
+{
+    delegate: Box<dyn TryCommand<Error = E>>,
+}
+
+impl<E> TryHeap<E>
+{
+    /// Creates a new [`TryHeap`] with the
+    /// supplied delegate.
+    #[must_use]
+    pub fn new(delegate: Box<dyn TryCommand<Error = E>>) -> TryHeap<E>
+    {
+        TryHeap { delegate }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<E> TryCommand for TryHeap<E>
+{
+    type Error = E;
+
+    delegate! {
+        to self.delegate {
+           fn try_execute(&self) -> Result<(), E>;
+        }
+    }
+}
+
+impl<E> From<Box<dyn TryCommand<Error = E>>> for TryHeap<E>
+{
+    fn from(delegate: Box<dyn TryCommand<Error = E>>) -> Self
+    {
+        TryHeap { delegate }
+    }
+}
+
+impl<D, E> From<D> for TryHeap<E>
+where
+    D: Fn() -> Result<(), E> + 'static,
+    E: 'static,
+{
+    fn from(delegate: D) -> Self
+    {
+        TryHeap::new(Box::new(Closure(delegate)))
+    }
+}
+
+/// Adapts a fallible closure into a
+/// [`TryCommand`].
+struct Closure<D>(D);
+
+impl<D, E> TryCommand for Closure<D>
+where
+    D: Fn() -> Result<(), E>,
+{
+    type Error = E;
+
+    fn try_execute(&self) -> Result<(), E>
+    {
+        (self.0)()
+    }
+}
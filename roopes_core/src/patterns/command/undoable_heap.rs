@@ -0,0 +1,65 @@
+//! Provides a heap-based implementation of
+//! [`UndoableCommand`].
+
+use crate::prelude::*;
+use delegate::delegate;
+
+/// Stores a delegate [`UndoableCommand`] in a
+/// [`Box`] for later use, allowing differently
+/// typed commands to share a
+/// [`super::CommandHistory`].  [`UndoableHeap`]s
+/// never merge.
+pub struct UndoableHeap
+{
+    delegate: Box<dyn UndoableCommand>,
+}
+
+impl UndoableHeap
+{
+    /// Creates a new [`UndoableHeap`] with the
+    /// supplied delegate.
+    #[must_use]
+    pub fn new(delegate: Box<dyn UndoableCommand>) -> UndoableHeap
+    {
+        UndoableHeap { delegate }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl Command for UndoableHeap
+{
+    delegate! {
+        to self.delegate {
+           fn execute(&self);
+        }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl UndoableCommand for UndoableHeap
+{
+    delegate! {
+        to self.delegate {
+           fn undo(&self);
+        }
+    }
+}
+
+impl From<Box<dyn UndoableCommand>> for UndoableHeap
+{
+    fn from(delegate: Box<dyn UndoableCommand>) -> Self
+    {
+        UndoableHeap { delegate }
+    }
+}
+
+impl<E, U> From<super::UndoableLambda<E, U>> for UndoableHeap
+where
+    E: Fn() + 'static,
+    U: Fn() + 'static,
+{
+    fn from(lambda: super::UndoableLambda<E, U>) -> Self
+    {
+        UndoableHeap::new(Box::new(lambda))
+    }
+}
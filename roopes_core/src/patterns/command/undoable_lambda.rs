@@ -0,0 +1,72 @@
+//! Provides an [`UndoableCommand`] built from a
+//! pair of closures.
+
+use crate::prelude::*;
+
+/// Delegates [`Command::execute`] and
+/// [`UndoableCommand::undo`] to a pair of
+/// closures.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::cell::Cell;
+///
+/// let value = Cell::new(0);
+/// let increment = command::UndoableLambda::new(
+///     || value.set(value.get() + 1),
+///     || value.set(value.get() - 1),
+/// );
+///
+/// increment.execute();
+/// assert_eq!(1, value.get());
+/// increment.undo();
+/// assert_eq!(0, value.get());
+/// ```
+pub struct UndoableLambda<E, U>
+where
+    E: Fn(),
+    U: Fn(),
+{
+    execute: E,
+    undo: U,
+}
+
+impl<E, U> UndoableLambda<E, U>
+where
+    E: Fn(),
+    U: Fn(),
+{
+    /// Creates an [`UndoableLambda`] from the
+    /// closures which perform and revert its
+    /// effects.
+    pub fn new(
+        execute: E,
+        undo: U,
+    ) -> UndoableLambda<E, U>
+    {
+        UndoableLambda { execute, undo }
+    }
+}
+
+impl<E, U> Command for UndoableLambda<E, U>
+where
+    E: Fn(),
+    U: Fn(),
+{
+    fn execute(&self)
+    {
+        (self.execute)();
+    }
+}
+
+impl<E, U> UndoableCommand for UndoableLambda<E, U>
+where
+    E: Fn(),
+    U: Fn(),
+{
+    fn undo(&self)
+    {
+        (self.undo)();
+    }
+}
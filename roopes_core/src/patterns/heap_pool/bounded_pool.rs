@@ -0,0 +1,291 @@
+//! Contains a [`BoundedPool`], which hands out
+//! [`Guard`]s that return their objects to the
+//! pool when dropped.
+
+use crate::prelude::*;
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    ops::Deref,
+};
+
+/// An Error which occurs during checkout.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CheckOutError
+{
+    /// Every object the pool may allocate is
+    /// already checked out.
+    Exhausted,
+}
+
+struct Reserve<T>
+{
+    idle: Vec<Box<RefCell<T>>>,
+    allocated: usize,
+}
+
+/// Holds between `min` and `max` allocated
+/// objects.  [`BoundedPool::check_out`] hands out
+/// a [`Guard`] which washes its object and
+/// returns it to the pool when dropped, so
+/// objects can't be leaked by forgetting to check
+/// them back in.  New objects are allocated one at
+/// a time, only when no idle object is
+/// available, and checkout fails once `max`
+/// objects are checked out.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::cell::RefCell;
+///
+/// let pool = heap_pool::bounded_pool::BoundedPool::new(
+///     1,
+///     2,
+///     emitter::Lambda::new(Vec::<u8>::new),
+///     handler::Lambda::new(|v: &RefCell<Vec<u8>>| v.borrow_mut().clear()),
+/// );
+///
+/// {
+///     let first = pool.check_out().unwrap();
+///     first.borrow_mut().push(1);
+///     let _second = pool.check_out().unwrap();
+///
+///     assert_eq!(
+///         Err(heap_pool::bounded_pool::CheckOutError::Exhausted),
+///         pool.check_out().map(|_| ())
+///     );
+/// }
+///
+/// assert_eq!(2, pool.idle());
+/// assert!(pool.check_out().unwrap().borrow().is_empty());
+/// ```
+pub struct BoundedPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    reserve: RefCell<Reserve<T>>,
+    min: usize,
+    max: usize,
+    new_gen: F,
+    washer: W,
+}
+
+impl<T, F, W> BoundedPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    /// Creates a new [`BoundedPool`], allocating
+    /// `min` objects up front.
+    ///
+    /// # Panics
+    /// If `min` is greater than `max`.
+    pub fn new(
+        min: usize,
+        max: usize,
+        new_gen: F,
+        washer: W,
+    ) -> BoundedPool<T, F, W>
+    {
+        assert!(
+            min <= max,
+            "A pool's minimum size cannot exceed its maximum."
+        );
+
+        let idle = (0..min)
+            .map(|_| Box::new(RefCell::new(new_gen.emit())))
+            .collect();
+
+        BoundedPool {
+            reserve: RefCell::new(Reserve {
+                idle,
+                allocated: min,
+            }),
+            min,
+            max,
+            new_gen,
+            washer,
+        }
+    }
+
+    /// Checks out an idle object, allocating one
+    /// if none is idle.
+    ///
+    /// # Errors
+    /// [`CheckOutError::Exhausted`]: `max` objects
+    /// are already checked out.
+    pub fn check_out(&self) -> Result<Guard<'_, T, F, W>, CheckOutError>
+    {
+        let mut reserve = self.reserve.borrow_mut();
+
+        let item = match reserve.idle.pop() {
+            | Some(item) => item,
+            | None if reserve.allocated < self.max => {
+                let item = Box::new(RefCell::new(self.new_gen.emit()));
+                reserve.allocated += 1;
+
+                item
+            }
+            | None => return Err(CheckOutError::Exhausted),
+        };
+
+        Ok(Guard {
+            item: Some(item),
+            pool: self,
+        })
+    }
+
+    /// Drops idle objects until no more than
+    /// `min` are allocated, giving back how many
+    /// were dropped.
+    pub fn shrink_to_idle(&self) -> usize
+    {
+        let mut reserve = self.reserve.borrow_mut();
+        let excess = reserve
+            .allocated
+            .saturating_sub(self.min)
+            .min(reserve.idle.len());
+
+        let keep = reserve.idle.len() - excess;
+        reserve.idle.truncate(keep);
+        reserve.allocated -= excess;
+
+        excess
+    }
+
+    /// Gives the number of allocated objects,
+    /// whether idle or checked out.
+    pub fn allocated(&self) -> usize
+    {
+        self.reserve.borrow().allocated
+    }
+
+    /// Gives the number of idle objects.
+    pub fn idle(&self) -> usize
+    {
+        self.reserve.borrow().idle.len()
+    }
+
+    /// Gives the number of objects which may be
+    /// kept allocated when idle.
+    pub fn min(&self) -> usize
+    {
+        self.min
+    }
+
+    /// Gives the number of objects which may be
+    /// allocated at once.
+    pub fn max(&self) -> usize
+    {
+        self.max
+    }
+
+    fn check_in(
+        &self,
+        item: Box<RefCell<T>>,
+    )
+    {
+        let reservation = Reservation { pool: self };
+        self.washer.handle(&item);
+        std::mem::forget(reservation);
+
+        self.reserve.borrow_mut().idle.push(item);
+    }
+}
+
+impl<T, F, W> Debug for BoundedPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("BoundedPool")
+            .field("allocated", &self.allocated())
+            .field("idle", &self.idle())
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Room held by an object being washed, which is
+/// given back if the washer panics.
+struct Reservation<'a, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    pool: &'a BoundedPool<T, F, W>,
+}
+
+impl<T, F, W> Drop for Reservation<'_, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    fn drop(&mut self)
+    {
+        self.pool.reserve.borrow_mut().allocated -= 1;
+    }
+}
+
+/// An object checked out of a [`BoundedPool`],
+/// which is washed and returned to the pool when
+/// dropped.
+pub struct Guard<'a, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    item: Option<Box<RefCell<T>>>,
+    pool: &'a BoundedPool<T, F, W>,
+}
+
+impl<T, F, W> Deref for Guard<'_, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    type Target = RefCell<T>;
+
+    fn deref(&self) -> &RefCell<T>
+    {
+        self.item
+            .as_ref()
+            .expect("Guard item was already returned.")
+    }
+}
+
+impl<T, F, W> Drop for Guard<'_, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    fn drop(&mut self)
+    {
+        if let Some(item) = self.item.take() {
+            self.pool.check_in(item);
+        }
+    }
+}
+
+impl<T, F, W> Debug for Guard<'_, T, F, W>
+where
+    T: Debug,
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_tuple("Guard").field(&**self).finish()
+    }
+}
@@ -12,7 +12,12 @@
 //!
 //! ![heap pool diagram][heap-pool-diagram]
 
+pub mod bounded_pool;
+pub mod mutex_box;
 pub mod refcell_box;
+pub mod slab;
+pub mod stats;
+pub mod sync_bounded_pool;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,184 @@
+//! Contains an implementation of [`HeapPool`]
+//! which hands out [`Mutex`]-guarded objects, so
+//! checked-out objects may be sent between
+//! threads.
+
+use super::{
+    stats::{
+        PoolEvent,
+        PoolStats,
+    },
+    HeapPool,
+};
+use crate::prelude::{
+    publisher_subscriber::{
+        sync_heap,
+        SyncVecPublisher,
+    },
+    *,
+};
+use std::{
+    sync::Mutex,
+    time::Instant,
+};
+
+/// Holds a list of allocated objects in a
+/// scalable pool.  Previously allocated
+/// objects can be checked back in after use, to
+/// prevent immediate deallocation.  Unlike
+/// [`super::refcell_box::RefCellBox`], pooled
+/// objects are wrapped in a [`Mutex`], so they
+/// are [`Send`] and [`Sync`] whenever `T` is
+/// [`Send`].  Keeps [`PoolStats`], and publishes
+/// a [`PoolEvent`] whenever the pool grows or an
+/// object is checked out or in.
+pub struct MutexBox<
+    T,
+    F,
+    W,
+    P = SyncVecPublisher<PoolEvent, sync_heap::Subscriber<PoolEvent>>,
+> where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+    P: Publisher<PoolEvent>,
+{
+    unused_pool: Vec<Box<Mutex<T>>>,
+    new_gen: F,
+    grow_size: u8,
+    washer: W,
+    stats: PoolStats,
+    publisher: P,
+}
+
+impl<T, F, W> MutexBox<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    /// Creates a new [`MutexBox`], publishing to
+    /// a [`SyncVecPublisher`] with no
+    /// subscribers.
+    pub fn new(
+        unused_pool: Vec<Box<Mutex<T>>>,
+        new_gen: F,
+        grow_size: u8,
+        washer: W,
+    ) -> MutexBox<T, F, W>
+    {
+        Self::with_publisher(
+            unused_pool,
+            new_gen,
+            grow_size,
+            washer,
+            SyncVecPublisher::default(),
+        )
+    }
+}
+
+impl<T, F, W, P> MutexBox<T, F, W, P>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+    P: Publisher<PoolEvent>,
+{
+    /// Creates a new [`MutexBox`], which
+    /// publishes its [`PoolEvent`]s to the given
+    /// [`Publisher`].
+    pub fn with_publisher(
+        unused_pool: Vec<Box<Mutex<T>>>,
+        new_gen: F,
+        grow_size: u8,
+        washer: W,
+        publisher: P,
+    ) -> MutexBox<T, F, W, P>
+    {
+        MutexBox {
+            stats: PoolStats::new(unused_pool.len()),
+            unused_pool,
+            new_gen,
+            grow_size,
+            washer,
+            publisher,
+        }
+    }
+
+    /// Gets the current number of un-checked-out
+    /// items in the pool.  The
+    /// current "reserve" size.
+    pub fn unused_pool_size(&self) -> usize
+    {
+        self.unused_pool.len()
+    }
+
+    /// Gives the [`PoolStats`] gathered so far.
+    pub fn stats(&self) -> PoolStats
+    {
+        self.stats
+    }
+
+    /// Gives the delegate [`Publisher`].
+    pub fn publisher(&self) -> &P
+    {
+        &self.publisher
+    }
+
+    /// Gives the delegate [`Publisher`] mutably,
+    /// e.g.: to attach subscribers.
+    pub fn publisher_mut(&mut self) -> &mut P
+    {
+        &mut self.publisher
+    }
+
+    /// Grows the [`MutexBox`] pool by the
+    /// previously specified quantity.
+    pub fn expand(&mut self)
+    {
+        (0..self.grow_size).for_each(|_| {
+            let pool_obj = Box::new(Mutex::new(self.new_gen.emit()));
+
+            self.unused_pool.push(pool_obj);
+        });
+
+        if let Some(event) = self.stats.record_expansion(self.grow_size.into())
+        {
+            self.publisher.publish(&event);
+        }
+    }
+}
+
+impl<T, F, W, P> HeapPool<Box<Mutex<T>>> for MutexBox<T, F, W, P>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+    P: Publisher<PoolEvent>,
+{
+    fn check_out(&mut self) -> Box<Mutex<T>>
+    {
+        if self.unused_pool.is_empty() {
+            let event = self.stats.record_miss();
+            self.publisher.publish(&event);
+
+            self.expand();
+        }
+
+        let container = self.unused_pool.pop().expect("Unused pool is empty.");
+
+        let event = self.stats.record_check_out();
+        self.publisher.publish(&event);
+
+        container
+    }
+
+    fn check_in(
+        &mut self,
+        container: Box<Mutex<T>>,
+    )
+    {
+        let started = Instant::now();
+        self.washer.handle(&container);
+        self.unused_pool.push(container);
+
+        let event = self.stats.record_check_in(started.elapsed());
+        self.publisher.publish(&event);
+    }
+}
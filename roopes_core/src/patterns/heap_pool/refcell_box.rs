@@ -2,26 +2,90 @@
 //! which allow client code to generate, use, and
 //! re-use heap-allocated objects efficiently.
 
-use super::HeapPool;
-use crate::prelude::*;
+use super::{
+    stats::{
+        PoolEvent,
+        PoolStats,
+    },
+    HeapPool,
+};
+use crate::prelude::{
+    publisher_subscriber::{
+        sync_heap,
+        VecPublisher,
+    },
+    *,
+};
 use std::{
     borrow::Borrow,
     cell::RefCell,
+    time::Instant,
 };
 
 /// Holds a list of allocated objects in a
 /// scalable pool.  Previously allocated
 /// objects can be checked back in after use, to
-/// prevent immediate deallocation.
-pub struct RefCellBox<T, F, W>
-where
+/// prevent immediate deallocation.  Keeps
+/// [`PoolStats`], and publishes a [`PoolEvent`]
+/// whenever the pool grows or an object is
+/// checked out or in.  The default publisher only
+/// takes [`Send`] and [`Sync`] subscribers, so the
+/// pool is [`Send`] whenever `T`, `F` and `W` are;
+/// use [`RefCellBox::with_publisher`] for any
+/// other [`Publisher`].
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     sync::{
+///         Arc,
+///         Mutex,
+///     },
+/// };
+///
+/// let events = Arc::new(Mutex::new(Vec::new()));
+/// let events_ext = events.clone();
+/// let mut pool = heap_pool::refcell_box::RefCellBox::new(
+///     Vec::new(),
+///     emitter::Lambda::new(|| 0_u32),
+///     2,
+///     handler::Lambda::new(|v: &RefCell<u32>| *v.borrow_mut() = 0),
+/// );
+/// pool.publisher_mut().attach(
+///     publisher_subscriber::sync_heap::Subscriber::new(Arc::new(
+///         SubscribingHandler::new(handler::Lambda::new(
+///             move |e: &heap_pool::stats::PoolEvent| {
+///                 events_ext.lock().unwrap().push(*e);
+///             },
+///         )),
+///     )),
+/// );
+///
+/// let item = pool.check_out();
+/// pool.check_in(item);
+///
+/// assert_eq!(2, pool.stats().allocated());
+/// assert_eq!(1, pool.stats().misses());
+/// assert_eq!(4, events.lock().unwrap().len());
+/// ```
+pub struct RefCellBox<
+    T,
+    F,
+    W,
+    P = VecPublisher<PoolEvent, sync_heap::Subscriber<PoolEvent>>,
+> where
     F: Emitter<T>,
     W: Handler<RefCell<T>>,
+    P: Publisher<PoolEvent>,
 {
     unused_pool: Vec<Box<RefCell<T>>>,
     new_gen: F,
     grow_size: u8,
     washer: W,
+    stats: PoolStats,
+    publisher: P,
 }
 
 impl<T, F, W> RefCellBox<T, F, W>
@@ -29,19 +93,49 @@ where
     F: Emitter<T>,
     W: Handler<RefCell<T>>,
 {
-    /// Creates a new [`RefCellBox`].
+    /// Creates a new [`RefCellBox`], publishing
+    /// to a [`VecPublisher`] with no subscribers.
     pub fn new(
         unused_pool: Vec<Box<RefCell<T>>>,
         new_gen: F,
         grow_size: u8,
         washer: W,
     ) -> RefCellBox<T, F, W>
+    {
+        Self::with_publisher(
+            unused_pool,
+            new_gen,
+            grow_size,
+            washer,
+            VecPublisher::default(),
+        )
+    }
+}
+
+impl<T, F, W, P> RefCellBox<T, F, W, P>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+    P: Publisher<PoolEvent>,
+{
+    /// Creates a new [`RefCellBox`], which
+    /// publishes its [`PoolEvent`]s to the given
+    /// [`Publisher`].
+    pub fn with_publisher(
+        unused_pool: Vec<Box<RefCell<T>>>,
+        new_gen: F,
+        grow_size: u8,
+        washer: W,
+        publisher: P,
+    ) -> RefCellBox<T, F, W, P>
     {
         RefCellBox {
+            stats: PoolStats::new(unused_pool.len()),
             unused_pool,
             new_gen,
             grow_size,
             washer,
+            publisher,
         }
     }
 
@@ -53,6 +147,25 @@ where
         self.unused_pool.len()
     }
 
+    /// Gives the [`PoolStats`] gathered so far.
+    pub fn stats(&self) -> PoolStats
+    {
+        self.stats
+    }
+
+    /// Gives the delegate [`Publisher`].
+    pub fn publisher(&self) -> &P
+    {
+        &self.publisher
+    }
+
+    /// Gives the delegate [`Publisher`] mutably,
+    /// e.g.: to attach subscribers.
+    pub fn publisher_mut(&mut self) -> &mut P
+    {
+        &mut self.publisher
+    }
+
     /// Grows the [`RefCellBox`] pool by the
     /// previously specified quantity.
     pub fn expand(&mut self)
@@ -62,21 +175,35 @@ where
 
             self.unused_pool.push(pool_obj);
         });
+
+        if let Some(event) = self.stats.record_expansion(self.grow_size.into())
+        {
+            self.publisher.publish(&event);
+        }
     }
 }
 
-impl<T, F, W> HeapPool<Box<RefCell<T>>> for RefCellBox<T, F, W>
+impl<T, F, W, P> HeapPool<Box<RefCell<T>>> for RefCellBox<T, F, W, P>
 where
     F: Emitter<T>,
     W: Handler<RefCell<T>>,
+    P: Publisher<PoolEvent>,
 {
     fn check_out(&mut self) -> Box<RefCell<T>>
     {
         if self.unused_pool.is_empty() {
+            let event = self.stats.record_miss();
+            self.publisher.publish(&event);
+
             self.expand();
         }
 
-        self.unused_pool.pop().expect("Unused pool is empty.")
+        let container = self.unused_pool.pop().expect("Unused pool is empty.");
+
+        let event = self.stats.record_check_out();
+        self.publisher.publish(&event);
+
+        container
     }
 
     fn check_in(
@@ -84,7 +211,11 @@ where
         container: Box<RefCell<T>>,
     )
     {
+        let started = Instant::now();
         self.washer.handle(container.borrow());
         self.unused_pool.push(container);
+
+        let event = self.stats.record_check_in(started.elapsed());
+        self.publisher.publish(&event);
     }
 }
@@ -0,0 +1,354 @@
+//! Contains a [`SlabPool`], an implementation of
+//! [`HeapPool`] which stores its objects
+//! contiguously and hands out generational
+//! [`Handle`]s to them.
+
+use super::HeapPool;
+use crate::prelude::*;
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+};
+
+/// An Error which occurs when using a [`Handle`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum HandleError
+{
+    /// The handle's object was checked in since
+    /// the handle was checked out.
+    Stale,
+}
+
+/// Identifies an object checked out of a
+/// [`SlabPool`].  Each time an object is checked
+/// in, its slot's generation advances, so older
+/// handles to it are detected as stale rather
+/// than reaching whichever object is checked out
+/// next.  A handle only identifies an object
+/// within the pool which gave it out: another
+/// pool can't tell it apart from its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Handle
+{
+    index: usize,
+    generation: u64,
+}
+
+impl Handle
+{
+    /// Gives the index of the object's slot.
+    #[must_use]
+    pub fn index(&self) -> usize
+    {
+        self.index
+    }
+
+    /// Gives the generation of the object's slot
+    /// when it was checked out.
+    #[must_use]
+    pub fn generation(&self) -> u64
+    {
+        self.generation
+    }
+}
+
+struct Slot<T>
+{
+    value: Option<T>,
+    generation: u64,
+    live: bool,
+}
+
+/// Holds allocated objects in a single [`Vec`],
+/// avoiding both a heap allocation per object and
+/// a runtime borrow check per access.  Checking
+/// out gives a [`Handle`], through which the
+/// object is reached with [`SlabPool::get`] and
+/// [`SlabPool::get_mut`].  Checking in washes the
+/// object, keeping it for re-use, and invalidates
+/// the handle.  As with
+/// [`super::refcell_box::RefCellBox`], the washer
+/// is given the object in a [`RefCell`], which is
+/// only created while it is being washed.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::{
+///     patterns::heap_pool::slab::HandleError,
+///     prelude::*,
+/// };
+/// use std::cell::RefCell;
+///
+/// let mut pool = heap_pool::slab::SlabPool::new(
+///     emitter::Lambda::new(Vec::<u8>::new),
+///     4,
+///     handler::Lambda::new(|v: &RefCell<Vec<u8>>| v.borrow_mut().clear()),
+/// );
+///
+/// let handle = pool.check_out();
+/// pool.get_mut(handle).unwrap().push(1);
+/// assert_eq!(vec![(handle, &vec![1])], pool.iter().collect::<Vec<_>>());
+///
+/// pool.check_in(handle);
+/// assert_eq!(Err(HandleError::Stale), pool.get(handle));
+///
+/// let reused = pool.check_out();
+/// assert_eq!(handle.index(), reused.index());
+/// assert!(pool.get(reused).unwrap().is_empty());
+/// ```
+pub struct SlabPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    slots: Vec<Slot<T>>,
+    unused: Vec<usize>,
+    new_gen: F,
+    grow_size: usize,
+    washer: W,
+}
+
+impl<T, F, W> SlabPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    /// Creates a new, empty [`SlabPool`], which
+    /// allocates `grow_size` objects whenever it
+    /// runs out.
+    pub fn new(
+        new_gen: F,
+        grow_size: usize,
+        washer: W,
+    ) -> SlabPool<T, F, W>
+    {
+        SlabPool {
+            slots: Vec::new(),
+            unused: Vec::new(),
+            new_gen,
+            grow_size: grow_size.max(1),
+            washer,
+        }
+    }
+
+    /// Gets the current number of un-checked-out
+    /// items in the pool.  The
+    /// current "reserve" size.
+    pub fn unused_pool_size(&self) -> usize
+    {
+        self.unused.len()
+    }
+
+    /// Gives the number of checked-out objects.
+    pub fn len(&self) -> usize
+    {
+        self.slots.len() - self.unused.len()
+    }
+
+    /// Indicates whether no objects are checked
+    /// out.
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// Grows the [`SlabPool`] by the previously
+    /// specified quantity.
+    pub fn expand(&mut self)
+    {
+        let start = self.slots.len();
+
+        self.slots.extend((0..self.grow_size).map(|_| {
+            Slot {
+                value: Some(self.new_gen.emit()),
+                generation: 0,
+                live: false,
+            }
+        }));
+        self.unused.extend((start..self.slots.len()).rev());
+    }
+
+    /// Gives the checked-out object the given
+    /// [`Handle`] refers to.
+    ///
+    /// # Errors
+    /// [`HandleError::Stale`]: The object was
+    /// checked in since the handle was given out.
+    pub fn get(
+        &self,
+        handle: Handle,
+    ) -> Result<&T, HandleError>
+    {
+        self.slots
+            .get(handle.index)
+            .filter(|slot| slot.live && slot.generation == handle.generation)
+            .and_then(|slot| slot.value.as_ref())
+            .ok_or(HandleError::Stale)
+    }
+
+    /// Gives the checked-out object the given
+    /// [`Handle`] refers to, mutably.
+    ///
+    /// # Errors
+    /// [`HandleError::Stale`]: The object was
+    /// checked in since the handle was given out.
+    pub fn get_mut(
+        &mut self,
+        handle: Handle,
+    ) -> Result<&mut T, HandleError>
+    {
+        self.slots
+            .get_mut(handle.index)
+            .filter(|slot| slot.live && slot.generation == handle.generation)
+            .and_then(|slot| slot.value.as_mut())
+            .ok_or(HandleError::Stale)
+    }
+
+    /// Washes the object the given [`Handle`]
+    /// refers to and keeps it for re-use,
+    /// invalidating the handle.
+    ///
+    /// # Errors
+    /// [`HandleError::Stale`]: The object was
+    /// already checked in.
+    pub fn try_check_in(
+        &mut self,
+        handle: Handle,
+    ) -> Result<(), HandleError>
+    {
+        let slot = self
+            .slots
+            .get_mut(handle.index)
+            .filter(|slot| slot.live && slot.generation == handle.generation)
+            .ok_or(HandleError::Stale)?;
+
+        let washing =
+            RefCell::new(slot.value.take().ok_or(HandleError::Stale)?);
+        slot.live = false;
+        slot.generation += 1;
+
+        let vacancy = Vacancy {
+            unused: &mut self.unused,
+            index: handle.index,
+        };
+        self.washer.handle(&washing);
+        slot.value = Some(washing.into_inner());
+        drop(vacancy);
+
+        Ok(())
+    }
+
+    /// Iterates over the checked-out objects,
+    /// along with their [`Handle`]s, in slot
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (Handle, &T)>
+    {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.live)
+            .filter_map(|(index, slot)| {
+                let handle = Handle {
+                    index,
+                    generation: slot.generation,
+                };
+
+                Some((handle, slot.value.as_ref()?))
+            })
+    }
+
+    /// Iterates mutably over the checked-out
+    /// objects, along with their [`Handle`]s, in
+    /// slot order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Handle, &mut T)>
+    {
+        self.slots
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, slot)| slot.live)
+            .filter_map(|(index, slot)| {
+                let handle = Handle {
+                    index,
+                    generation: slot.generation,
+                };
+
+                Some((handle, slot.value.as_mut()?))
+            })
+    }
+}
+
+impl<T, F, W> HeapPool<Handle> for SlabPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    fn check_out(&mut self) -> Handle
+    {
+        if self.unused.is_empty() {
+            self.expand();
+        }
+
+        let index = *self.unused.last().expect("Unused pool is empty.");
+        let slot = &mut self.slots[index];
+        if slot.value.is_none() {
+            slot.value = Some(self.new_gen.emit());
+        }
+        self.unused.pop();
+        slot.live = true;
+
+        Handle {
+            index,
+            generation: slot.generation,
+        }
+    }
+
+    /// # Panics
+    /// If the [`Handle`] is stale, i.e.: its
+    /// object was already checked in.  See
+    /// [`SlabPool::try_check_in`].
+    fn check_in(
+        &mut self,
+        handle: Handle,
+    )
+    {
+        self.try_check_in(handle)
+            .expect("Handle was already checked in.");
+    }
+}
+
+/// Returns a checked-in slot to the unused list,
+/// even if the washer panics.  A slot whose object
+/// was lost that way is refilled from the emitter
+/// when next checked out.
+struct Vacancy<'a>
+{
+    unused: &'a mut Vec<usize>,
+    index: usize,
+}
+
+impl Drop for Vacancy<'_>
+{
+    fn drop(&mut self)
+    {
+        self.unused.push(self.index);
+    }
+}
+
+impl<T, F, W> Debug for SlabPool<T, F, W>
+where
+    T: Debug,
+    F: Emitter<T>,
+    W: Handler<RefCell<T>>,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("SlabPool")
+            .field("live", &self.iter().collect::<Vec<_>>())
+            .field("unused", &self.unused.len())
+            .field("grow_size", &self.grow_size)
+            .finish_non_exhaustive()
+    }
+}
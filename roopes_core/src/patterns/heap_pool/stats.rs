@@ -0,0 +1,147 @@
+//! Contains [`PoolStats`], which instrument a
+//! [`super::HeapPool`], and the [`PoolEvent`]s
+//! it publishes as its objects are allocated,
+//! checked out, and checked in.
+
+use std::time::Duration;
+
+/// Something which happened to a
+/// [`super::HeapPool`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolEvent
+{
+    /// A checkout found no unused object.
+    Missed,
+    /// The pool allocated `count` new objects.
+    Expanded
+    {
+        /// The number of objects allocated.
+        count: usize,
+    },
+    /// An object was checked out.
+    CheckedOut,
+    /// An object was washed and checked in.
+    CheckedIn
+    {
+        /// The time the washer took.
+        wash_time: Duration,
+    },
+}
+
+/// Counts what a [`super::HeapPool`] has done
+/// over its lifetime, to help tune its sizes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolStats
+{
+    allocated: usize,
+    checked_out: usize,
+    peak_checked_out: usize,
+    expansions: usize,
+    wash_time: Duration,
+    misses: usize,
+}
+
+impl PoolStats
+{
+    /// Creates [`PoolStats`] for a pool which
+    /// already holds `allocated` objects.
+    #[must_use]
+    pub fn new(allocated: usize) -> PoolStats
+    {
+        PoolStats {
+            allocated,
+            ..PoolStats::default()
+        }
+    }
+
+    /// Gives the total number of objects the pool
+    /// has allocated.
+    #[must_use]
+    pub fn allocated(&self) -> usize
+    {
+        self.allocated
+    }
+
+    /// Gives the number of objects currently
+    /// checked out.
+    #[must_use]
+    pub fn checked_out(&self) -> usize
+    {
+        self.checked_out
+    }
+
+    /// Gives the largest number of objects which
+    /// have been checked out at once.
+    #[must_use]
+    pub fn peak_checked_out(&self) -> usize
+    {
+        self.peak_checked_out
+    }
+
+    /// Gives the number of times the pool has
+    /// grown.  Expansions which allocated no
+    /// objects aren't counted.
+    #[must_use]
+    pub fn expansions(&self) -> usize
+    {
+        self.expansions
+    }
+
+    /// Gives the total time spent washing
+    /// checked-in objects.
+    #[must_use]
+    pub fn wash_time(&self) -> Duration
+    {
+        self.wash_time
+    }
+
+    /// Gives the number of checkouts which found
+    /// no unused object, and had to wait for the
+    /// pool to grow.
+    #[must_use]
+    pub fn misses(&self) -> usize
+    {
+        self.misses
+    }
+
+    pub(super) fn record_miss(&mut self) -> PoolEvent
+    {
+        self.misses += 1;
+
+        PoolEvent::Missed
+    }
+
+    pub(super) fn record_expansion(
+        &mut self,
+        count: usize,
+    ) -> Option<PoolEvent>
+    {
+        if count == 0 {
+            return None;
+        }
+
+        self.allocated += count;
+        self.expansions += 1;
+
+        Some(PoolEvent::Expanded { count })
+    }
+
+    pub(super) fn record_check_out(&mut self) -> PoolEvent
+    {
+        self.checked_out += 1;
+        self.peak_checked_out = self.peak_checked_out.max(self.checked_out);
+
+        PoolEvent::CheckedOut
+    }
+
+    pub(super) fn record_check_in(
+        &mut self,
+        wash_time: Duration,
+    ) -> PoolEvent
+    {
+        self.checked_out = self.checked_out.saturating_sub(1);
+        self.wash_time += wash_time;
+
+        PoolEvent::CheckedIn { wash_time }
+    }
+}
@@ -0,0 +1,390 @@
+//! Contains a [`SyncBoundedPool`], a
+//! [`Mutex`]-guarded counterpart of
+//! [`super::bounded_pool::BoundedPool`] which may be shared
+//! between threads.
+
+use super::bounded_pool::CheckOutError;
+use crate::prelude::*;
+use std::{
+    fmt::Debug,
+    ops::Deref,
+    sync::{
+        Condvar,
+        Mutex,
+        MutexGuard,
+    },
+    time::Duration,
+};
+
+struct Reserve<T>
+{
+    idle: Vec<Box<Mutex<T>>>,
+    allocated: usize,
+}
+
+/// Holds between `min` and `max` allocated
+/// objects, behind a [`Mutex`], so that it is
+/// [`Sync`] whenever `T`, the emitter and the
+/// washer are [`Send`] and [`Sync`].  Checkouts
+/// hand out [`Guard`]s which wash their objects
+/// and return them to the pool when dropped.
+///
+/// Once `max` objects are checked out,
+/// [`SyncBoundedPool::check_out`] blocks until one
+/// is returned, [`SyncBoundedPool::check_out_timeout`]
+/// blocks for at most the given time, and
+/// [`SyncBoundedPool::try_check_out`] fails
+/// immediately.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::sync::{
+///     Arc,
+///     Mutex,
+/// };
+///
+/// let pool = Arc::new(heap_pool::sync_bounded_pool::SyncBoundedPool::new(
+///     0,
+///     1,
+///     emitter::Lambda::new(|| 0_u32),
+///     handler::Lambda::new(|_: &Mutex<u32>| {}),
+/// ));
+///
+/// let first = pool.check_out();
+/// assert!(pool.try_check_out().is_err());
+///
+/// let worker_pool = pool.clone();
+/// let worker = std::thread::spawn(move || {
+///     *worker_pool.check_out().lock().unwrap() += 1;
+/// });
+///
+/// *first.lock().unwrap() += 1;
+/// drop(first);
+/// worker.join().unwrap();
+///
+/// assert_eq!(2, *pool.check_out().lock().unwrap());
+/// ```
+pub struct SyncBoundedPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    reserve: Mutex<Reserve<T>>,
+    returned: Condvar,
+    min: usize,
+    max: usize,
+    new_gen: F,
+    washer: W,
+}
+
+impl<T, F, W> SyncBoundedPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    /// Creates a new [`SyncBoundedPool`],
+    /// allocating `min` objects up front.
+    ///
+    /// # Panics
+    /// If `min` is greater than `max`.
+    pub fn new(
+        min: usize,
+        max: usize,
+        new_gen: F,
+        washer: W,
+    ) -> SyncBoundedPool<T, F, W>
+    {
+        assert!(
+            min <= max,
+            "A pool's minimum size cannot exceed its maximum."
+        );
+
+        let idle = (0..min)
+            .map(|_| Box::new(Mutex::new(new_gen.emit())))
+            .collect();
+
+        SyncBoundedPool {
+            reserve: Mutex::new(Reserve {
+                idle,
+                allocated: min,
+            }),
+            returned: Condvar::new(),
+            min,
+            max,
+            new_gen,
+            washer,
+        }
+    }
+
+    /// Checks out an idle object, allocating one
+    /// if none is idle, and blocking until one is
+    /// returned if `max` are checked out.
+    ///
+    /// # Panics
+    /// If the pool's lock was poisoned.
+    pub fn check_out(&self) -> Guard<'_, T, F, W>
+    {
+        let reserve = self
+            .returned
+            .wait_while(self.lock(), |reserve| self.is_exhausted(reserve))
+            .expect("SyncBoundedPool lock was poisoned.");
+
+        self.take(reserve)
+    }
+
+    /// Checks out an idle object, allocating one
+    /// if none is idle, and blocking for at most
+    /// `timeout` for one to be returned if `max`
+    /// are checked out.
+    ///
+    /// # Errors
+    /// [`CheckOutError::Exhausted`]: No object
+    /// was returned in time.
+    ///
+    /// # Panics
+    /// If the pool's lock was poisoned.
+    pub fn check_out_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Guard<'_, T, F, W>, CheckOutError>
+    {
+        let (reserve, _) = self
+            .returned
+            .wait_timeout_while(self.lock(), timeout, |reserve| {
+                self.is_exhausted(reserve)
+            })
+            .expect("SyncBoundedPool lock was poisoned.");
+
+        if self.is_exhausted(&reserve) {
+            Err(CheckOutError::Exhausted)
+        } else {
+            Ok(self.take(reserve))
+        }
+    }
+
+    /// Checks out an idle object, allocating one
+    /// if none is idle, without blocking.
+    ///
+    /// # Errors
+    /// [`CheckOutError::Exhausted`]: `max` objects
+    /// are already checked out.
+    ///
+    /// # Panics
+    /// If the pool's lock was poisoned.
+    pub fn try_check_out(&self) -> Result<Guard<'_, T, F, W>, CheckOutError>
+    {
+        let reserve = self.lock();
+
+        if self.is_exhausted(&reserve) {
+            Err(CheckOutError::Exhausted)
+        } else {
+            Ok(self.take(reserve))
+        }
+    }
+
+    /// Drops idle objects until no more than
+    /// `min` are allocated, giving back how many
+    /// were dropped.
+    ///
+    /// # Panics
+    /// If the pool's lock was poisoned.
+    pub fn shrink_to_idle(&self) -> usize
+    {
+        let mut reserve = self.lock();
+        let excess = reserve
+            .allocated
+            .saturating_sub(self.min)
+            .min(reserve.idle.len());
+
+        let keep = reserve.idle.len() - excess;
+        reserve.idle.truncate(keep);
+        reserve.allocated -= excess;
+
+        excess
+    }
+
+    /// Gives the number of allocated objects,
+    /// whether idle or checked out.
+    ///
+    /// # Panics
+    /// If the pool's lock was poisoned.
+    pub fn allocated(&self) -> usize
+    {
+        self.lock().allocated
+    }
+
+    /// Gives the number of idle objects.
+    ///
+    /// # Panics
+    /// If the pool's lock was poisoned.
+    pub fn idle(&self) -> usize
+    {
+        self.lock().idle.len()
+    }
+
+    /// Gives the number of objects which may be
+    /// kept allocated when idle.
+    pub fn min(&self) -> usize
+    {
+        self.min
+    }
+
+    /// Gives the number of objects which may be
+    /// allocated at once.
+    pub fn max(&self) -> usize
+    {
+        self.max
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Reserve<T>>
+    {
+        self.reserve
+            .lock()
+            .expect("SyncBoundedPool lock was poisoned.")
+    }
+
+    fn is_exhausted(
+        &self,
+        reserve: &Reserve<T>,
+    ) -> bool
+    {
+        reserve.idle.is_empty() && reserve.allocated >= self.max
+    }
+
+    /// Takes an idle object, or reserves room for
+    /// a new one and allocates it once the lock
+    /// is released.  The reserve must not be
+    /// exhausted.
+    fn take(
+        &self,
+        mut reserve: MutexGuard<'_, Reserve<T>>,
+    ) -> Guard<'_, T, F, W>
+    {
+        let item = reserve.idle.pop().unwrap_or_else(|| {
+            reserve.allocated += 1;
+            drop(reserve);
+
+            let reservation = Reservation { pool: self };
+            let item = Box::new(Mutex::new(self.new_gen.emit()));
+            std::mem::forget(reservation);
+
+            item
+        });
+
+        Guard {
+            item: Some(item),
+            pool: self,
+        }
+    }
+
+    fn check_in(
+        &self,
+        item: Box<Mutex<T>>,
+    )
+    {
+        let reservation = Reservation { pool: self };
+        self.washer.handle(&item);
+        std::mem::forget(reservation);
+
+        self.lock().idle.push(item);
+        self.returned.notify_one();
+    }
+}
+
+impl<T, F, W> Debug for SyncBoundedPool<T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("SyncBoundedPool")
+            .field("allocated", &self.allocated())
+            .field("idle", &self.idle())
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Room reserved for an object being allocated or
+/// washed outside the pool's lock, which is given
+/// back if the emitter or washer panics.
+struct Reservation<'a, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    pool: &'a SyncBoundedPool<T, F, W>,
+}
+
+impl<T, F, W> Drop for Reservation<'_, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    fn drop(&mut self)
+    {
+        self.pool.lock().allocated -= 1;
+        self.pool.returned.notify_one();
+    }
+}
+
+/// An object checked out of a
+/// [`SyncBoundedPool`], which is washed and
+/// returned to the pool when dropped.
+pub struct Guard<'a, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    item: Option<Box<Mutex<T>>>,
+    pool: &'a SyncBoundedPool<T, F, W>,
+}
+
+impl<T, F, W> Deref for Guard<'_, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    type Target = Mutex<T>;
+
+    fn deref(&self) -> &Mutex<T>
+    {
+        self.item
+            .as_ref()
+            .expect("Guard item was already returned.")
+    }
+}
+
+impl<T, F, W> Drop for Guard<'_, T, F, W>
+where
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    fn drop(&mut self)
+    {
+        if let Some(item) = self.item.take() {
+            self.pool.check_in(item);
+        }
+    }
+}
+
+impl<T, F, W> Debug for Guard<'_, T, F, W>
+where
+    T: Debug,
+    F: Emitter<T>,
+    W: Handler<Mutex<T>>,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_tuple("Guard").field(&**self).finish()
+    }
+}
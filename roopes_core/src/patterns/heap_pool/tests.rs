@@ -1,6 +1,13 @@
-use super::refcell_box::RefCellBox;
+use super::{
+    refcell_box::RefCellBox,
+    stats::PoolEvent,
+};
 use crate::prelude::{
     command::heap,
+    publisher_subscriber::{
+        sync_heap,
+        VecPublisher,
+    },
     *,
 };
 use std::{
@@ -10,6 +17,10 @@ use std::{
         RefCell,
     },
     rc::Rc,
+    sync::{
+        Arc,
+        Mutex,
+    },
 };
 
 struct TestObj
@@ -99,18 +110,461 @@ fn heap_pool_washing()
 
     let test_contents = heap_pool.check_out();
 
-    assert_eq!(true, test_contents.borrow().clean);
-    assert_eq!(true, test_contents.borrow().new);
+    assert!(test_contents.borrow().clean);
+    assert!(test_contents.borrow().new);
 
     use_test_obj(&test_contents);
 
-    assert_eq!(false, test_contents.borrow().clean);
-    assert_eq!(false, test_contents.borrow().new);
+    assert!(!test_contents.borrow().clean);
+    assert!(!test_contents.borrow().new);
 
     heap_pool.check_in(test_contents);
 
     let test_contents = heap_pool.check_out();
 
-    assert_eq!(true, test_contents.borrow().clean);
-    assert_eq!(false, test_contents.borrow().new);
+    assert!(test_contents.borrow().clean);
+    assert!(!test_contents.borrow().new);
+}
+
+#[test]
+fn mutex_box_checked_out_across_threads()
+{
+    use super::mutex_box::MutexBox;
+    use std::sync::Mutex;
+
+    let mut heap_pool: MutexBox<
+        TestObj,
+        emitter::Lambda<_, _>,
+        handler::Lambda<_, _>,
+    > = MutexBox::new(
+        Vec::new(),
+        create_test_obj.into(),
+        5,
+        (|test_obj: &Mutex<TestObj>| {
+            test_obj.lock().unwrap().clean = true;
+        })
+        .into(),
+    );
+
+    let test_contents = heap_pool.check_out();
+
+    let test_contents = std::thread::spawn(move || {
+        {
+            let mut test_obj = test_contents.lock().unwrap();
+            test_obj.new = false;
+            test_obj.clean = false;
+        }
+        test_contents
+    })
+    .join()
+    .unwrap();
+
+    assert!(!test_contents.lock().unwrap().clean);
+
+    heap_pool.check_in(test_contents);
+
+    let test_contents = heap_pool.check_out();
+
+    assert!(test_contents.lock().unwrap().clean);
+    assert!(!test_contents.lock().unwrap().new);
+}
+
+#[test]
+fn bounded_pool_guards_wash_and_return()
+{
+    use super::bounded_pool::{
+        BoundedPool,
+        CheckOutError,
+    };
+
+    let heap_pool = BoundedPool::new(
+        1,
+        2,
+        emitter::Lambda::new(create_test_obj),
+        handler::Lambda::new(clean_test_obj),
+    );
+
+    assert_eq!(1, heap_pool.allocated());
+
+    {
+        let first = heap_pool.check_out().unwrap();
+        use_test_obj(&first);
+        let _second = heap_pool.check_out().unwrap();
+
+        assert_eq!(2, heap_pool.allocated());
+        assert_eq!(
+            Err(CheckOutError::Exhausted),
+            heap_pool.check_out().map(|_| ())
+        );
+    }
+
+    assert_eq!(2, heap_pool.idle());
+
+    let used = heap_pool.check_out().unwrap();
+    let fresh = heap_pool.check_out().unwrap();
+    assert!(used.borrow().clean && fresh.borrow().clean);
+    assert!(!used.borrow().new || !fresh.borrow().new);
+}
+
+#[test]
+fn bounded_pool_shrinks_to_idle()
+{
+    use super::bounded_pool::BoundedPool;
+
+    let heap_pool = BoundedPool::new(
+        1,
+        3,
+        emitter::Lambda::new(create_test_obj),
+        handler::Lambda::new(clean_test_obj),
+    );
+
+    let guards: Vec<_> =
+        (0..3).map(|_| heap_pool.check_out().unwrap()).collect();
+    assert_eq!(0, heap_pool.shrink_to_idle());
+
+    drop(guards);
+    assert_eq!(2, heap_pool.shrink_to_idle());
+    assert_eq!(1, heap_pool.allocated());
+    assert_eq!(1, heap_pool.idle());
+}
+
+#[test]
+fn sync_bounded_pool_blocks_until_returned()
+{
+    use super::{
+        bounded_pool::CheckOutError,
+        sync_bounded_pool::SyncBoundedPool,
+    };
+    use std::{
+        sync::{
+            mpsc,
+            Arc,
+            Mutex,
+        },
+        time::Duration,
+    };
+
+    let heap_pool = Arc::new(SyncBoundedPool::new(
+        0,
+        1,
+        emitter::Lambda::new(create_test_obj),
+        handler::Lambda::new(|test_obj: &Mutex<TestObj>| {
+            test_obj.lock().unwrap().clean = true;
+        }),
+    ));
+
+    let held = heap_pool.check_out();
+    held.lock().unwrap().clean = false;
+
+    assert_eq!(
+        Err(CheckOutError::Exhausted),
+        heap_pool.try_check_out().map(|_| ())
+    );
+    assert_eq!(
+        Err(CheckOutError::Exhausted),
+        heap_pool
+            .check_out_timeout(Duration::from_millis(10))
+            .map(|_| ())
+    );
+
+    let (sender, receiver) = mpsc::channel();
+    let waiting_pool = heap_pool.clone();
+    let waiter = std::thread::spawn(move || {
+        sender.send(()).unwrap();
+        let test_obj = waiting_pool.check_out();
+        let test_obj = test_obj.lock().unwrap();
+        test_obj.clean && !test_obj.new
+    });
+
+    receiver.recv().unwrap();
+    held.lock().unwrap().new = false;
+    drop(held);
+
+    assert!(waiter.join().unwrap());
+    assert_eq!(1, heap_pool.allocated());
+    assert_eq!(1, heap_pool.idle());
+}
+
+#[test]
+fn sync_bounded_pool_releases_room_when_emitter_panics()
+{
+    use super::sync_bounded_pool::SyncBoundedPool;
+    use std::{
+        panic::{
+            self,
+            AssertUnwindSafe,
+        },
+        sync::{
+            atomic::{
+                AtomicBool,
+                Ordering,
+            },
+            Mutex,
+        },
+    };
+
+    let panicked = AtomicBool::new(false);
+    let heap_pool = SyncBoundedPool::new(
+        0,
+        1,
+        emitter::Lambda::new(|| {
+            assert!(panicked.swap(true, Ordering::SeqCst), "emitter failed");
+            create_test_obj()
+        }),
+        handler::Lambda::new(|_: &Mutex<TestObj>| {}),
+    );
+
+    let failed =
+        panic::catch_unwind(AssertUnwindSafe(|| drop(heap_pool.check_out())));
+
+    assert!(failed.is_err());
+    assert_eq!(0, heap_pool.allocated());
+    assert!(heap_pool.try_check_out().is_ok());
+}
+
+fn record_events(
+    publisher: &mut VecPublisher<PoolEvent, sync_heap::Subscriber<PoolEvent>>
+) -> Arc<Mutex<Vec<PoolEvent>>>
+{
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_ext = events.clone();
+
+    publisher.attach(sync_heap::Subscriber::new(Arc::new(
+        SubscribingHandler::new(handler::Lambda::new(
+            move |event: &PoolEvent| {
+                events_ext.lock().unwrap().push(*event);
+            },
+        )),
+    )));
+
+    events
+}
+
+#[test]
+fn heap_pool_is_send()
+{
+    fn assert_send<T: Send>() {}
+
+    assert_send::<
+        RefCellBox<
+            TestObj,
+            emitter::Lambda<fn() -> TestObj, TestObj>,
+            handler::Lambda<fn(&RefCell<TestObj>), RefCell<TestObj>>,
+        >,
+    >();
+}
+
+#[test]
+fn bounded_pool_releases_room_when_washer_panics()
+{
+    use super::bounded_pool::BoundedPool;
+    use std::panic::{
+        self,
+        AssertUnwindSafe,
+    };
+
+    let heap_pool = BoundedPool::new(
+        0,
+        1,
+        emitter::Lambda::new(create_test_obj),
+        handler::Lambda::new(|test_obj: &RefCell<TestObj>| {
+            assert!(test_obj.borrow().new, "washer failed");
+        }),
+    );
+
+    let failed = panic::catch_unwind(AssertUnwindSafe(|| {
+        use_test_obj(&heap_pool.check_out().unwrap());
+    }));
+
+    assert!(failed.is_err());
+    assert_eq!(0, heap_pool.allocated());
+    assert_eq!(0, heap_pool.idle());
+    assert!(heap_pool.check_out().is_ok());
+}
+
+#[test]
+fn sync_bounded_pool_releases_room_when_washer_panics()
+{
+    use super::sync_bounded_pool::SyncBoundedPool;
+    use std::panic::{
+        self,
+        AssertUnwindSafe,
+    };
+
+    let heap_pool = SyncBoundedPool::new(
+        0,
+        1,
+        emitter::Lambda::new(create_test_obj),
+        handler::Lambda::new(|test_obj: &Mutex<TestObj>| {
+            let new = test_obj.lock().unwrap().new;
+            assert!(new, "washer failed");
+        }),
+    );
+
+    let failed = panic::catch_unwind(AssertUnwindSafe(|| {
+        heap_pool.check_out().lock().unwrap().new = false;
+    }));
+
+    assert!(failed.is_err());
+    assert_eq!(0, heap_pool.allocated());
+    assert_eq!(0, heap_pool.idle());
+    assert!(heap_pool.try_check_out().is_ok());
+}
+
+#[test]
+fn heap_pool_stats_and_events()
+{
+    let mut heap_pool: RefCellBox<
+        TestObj,
+        emitter::Lambda<_, _>,
+        handler::Lambda<_, _>,
+    > = RefCellBox::new(
+        Vec::new(),
+        create_test_obj.into(),
+        2,
+        clean_test_obj.into(),
+    );
+    let events = record_events(heap_pool.publisher_mut());
+
+    let first = heap_pool.check_out();
+    let second = heap_pool.check_out();
+    heap_pool.check_in(first);
+    let third = heap_pool.check_out();
+    let fourth = heap_pool.check_out();
+    heap_pool.check_in(second);
+    heap_pool.check_in(third);
+    heap_pool.check_in(fourth);
+
+    let stats = heap_pool.stats();
+    assert_eq!(4, stats.allocated());
+    assert_eq!(0, stats.checked_out());
+    assert_eq!(3, stats.peak_checked_out());
+    assert_eq!(2, stats.expansions());
+    assert_eq!(2, stats.misses());
+
+    let events = events.lock().unwrap();
+    assert_eq!(
+        vec![
+            PoolEvent::Missed,
+            PoolEvent::Expanded { count: 2 },
+            PoolEvent::CheckedOut,
+            PoolEvent::CheckedOut,
+        ],
+        events[..4]
+    );
+    assert_eq!(
+        stats.wash_time(),
+        events
+            .iter()
+            .filter_map(|event| {
+                match event {
+                    | PoolEvent::CheckedIn { wash_time } => Some(*wash_time),
+                    | _ => None,
+                }
+            })
+            .sum()
+    );
+}
+
+#[test]
+fn heap_pool_empty_expansion_is_not_counted()
+{
+    let mut heap_pool: RefCellBox<
+        TestObj,
+        emitter::Lambda<_, _>,
+        handler::Lambda<_, _>,
+    > = RefCellBox::new(
+        Vec::new(),
+        create_test_obj.into(),
+        0,
+        clean_test_obj.into(),
+    );
+    let events = record_events(heap_pool.publisher_mut());
+
+    heap_pool.expand();
+
+    assert_eq!(0, heap_pool.unused_pool_size());
+    assert_eq!(0, heap_pool.stats().allocated());
+    assert_eq!(0, heap_pool.stats().expansions());
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn slab_pool_detects_stale_handles()
+{
+    use super::slab::{
+        HandleError,
+        SlabPool,
+    };
+
+    let mut heap_pool = SlabPool::new(
+        emitter::Lambda::new(create_test_obj),
+        2,
+        handler::Lambda::new(|test_obj: &RefCell<TestObj>| {
+            test_obj.borrow_mut().clean = true;
+        }),
+    );
+
+    let first = heap_pool.check_out();
+    let second = heap_pool.check_out();
+    assert_eq!(0, heap_pool.unused_pool_size());
+
+    heap_pool.get_mut(first).unwrap().clean = false;
+    heap_pool.check_in(first);
+
+    assert_eq!(Err(HandleError::Stale), heap_pool.get(first).map(|_| ()));
+    assert_eq!(Err(HandleError::Stale), heap_pool.try_check_in(first));
+
+    let reused = heap_pool.check_out();
+    assert_eq!(first.index(), reused.index());
+    assert_ne!(first, reused);
+    assert!(heap_pool.get(reused).unwrap().clean);
+    assert_eq!(Err(HandleError::Stale), heap_pool.get(first).map(|_| ()));
+
+    let third = heap_pool.check_out();
+    assert_eq!(4, heap_pool.len() + heap_pool.unused_pool_size());
+
+    for (_, test_obj) in heap_pool.iter_mut() {
+        test_obj.new = false;
+    }
+    heap_pool.check_in(second);
+
+    let live: Vec<_> = heap_pool.iter().map(|(handle, _)| handle).collect();
+    assert_eq!(vec![reused, third], live);
+    assert!(heap_pool.iter().all(|(_, test_obj)| !test_obj.new));
+}
+
+#[test]
+fn slab_pool_keeps_slot_when_washer_panics()
+{
+    use super::slab::{
+        HandleError,
+        SlabPool,
+    };
+    use std::panic::{
+        self,
+        AssertUnwindSafe,
+    };
+
+    let mut heap_pool = SlabPool::new(
+        emitter::Lambda::new(create_test_obj),
+        1,
+        handler::Lambda::new(|test_obj: &RefCell<TestObj>| {
+            assert!(test_obj.borrow().new, "washer failed");
+        }),
+    );
+
+    let handle = heap_pool.check_out();
+    heap_pool.get_mut(handle).unwrap().new = false;
+
+    let failed =
+        panic::catch_unwind(AssertUnwindSafe(|| heap_pool.check_in(handle)));
+
+    assert!(failed.is_err());
+    assert_eq!(1, heap_pool.unused_pool_size());
+    assert_eq!(Err(HandleError::Stale), heap_pool.get(handle).map(|_| ()));
+
+    let reused = heap_pool.check_out();
+    assert_eq!(handle.index(), reused.index());
+    assert!(heap_pool.get(reused).unwrap().new);
 }
@@ -3,11 +3,13 @@
 //! OOP-style patterns.
 
 pub mod abstract_factory;
+pub mod builder;
 pub mod command;
 pub mod heap_pool;
 pub mod observer;
 pub mod publisher_subscriber;
 pub mod state;
+pub mod subscription;
 pub mod transformer_chain;
 
 /// Exposes submodules and their preludes at the
@@ -16,17 +18,21 @@ pub mod prelude
 {
     pub use super::{
         abstract_factory,
+        builder,
         command,
         heap_pool,
         observer,
         publisher_subscriber,
         state,
+        subscription,
         transformer_chain,
     };
     pub use abstract_factory::prelude::*;
+    pub use builder::prelude::*;
     pub use command::prelude::*;
     pub use heap_pool::prelude::*;
     pub use observer::prelude::*;
     pub use publisher_subscriber::prelude::*;
     pub use state::prelude::*;
+    pub use subscription::prelude::*;
 }
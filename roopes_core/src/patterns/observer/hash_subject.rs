@@ -6,11 +6,15 @@ use super::{
     Observer,
     Subject,
 };
-use crate::prelude::*;
+use crate::{
+    patterns::subscription::registry::Registry,
+    prelude::*,
+};
 use std::{
-    borrow::BorrowMut,
-    cell::RefCell,
-    collections::HashSet,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     hash::Hash,
 };
 
@@ -22,6 +26,18 @@ pub trait HashSetObserver = Observer + Eq + Hash;
 /// Implements [`Subject`] backed by a
 /// [`HashSet<T>`]. `T` must implement
 /// [`PartialEq`] and  [`Hash`].
+/// [`HashSubject::subscribe`] attaches an
+/// observer for the lifetime of a
+/// [`Subscription`].
+///
+/// Observers may subscribe, or drop their
+/// [`Subscription`]s, from within
+/// [`Subject::notify`].  Observers subscribed
+/// during a notification first receive the next
+/// one; observers unsubscribed during a
+/// notification receive nothing further,
+/// including the rest of the notification in
+/// progress.
 ///
 /// # Examples
 /// ``` rust
@@ -51,7 +67,7 @@ pub struct HashSubject<O>
 where
     O: HashSetObserver,
 {
-    listeners: HashSet<O>,
+    listeners: Registry<HashMap<O, Vec<usize>>>,
 }
 
 impl<O> Default for HashSubject<O>
@@ -75,7 +91,32 @@ where
     #[must_use]
     pub fn new(listeners: HashSet<O>) -> HashSubject<O>
     {
-        HashSubject { listeners }
+        let listeners: HashMap<_, _> = listeners
+            .into_iter()
+            .enumerate()
+            .map(|(id, observer)| (observer, vec![id]))
+            .collect();
+        let next_id = listeners.len();
+
+        HashSubject {
+            listeners: Registry::new(listeners, next_id),
+        }
+    }
+
+    /// Attaches the given [`Observer`] until the
+    /// returned [`Subscription`] is dropped.  If
+    /// an equal observer is already attached, the
+    /// given one is discarded, and the existing
+    /// one stays attached until
+    /// [`DetachableSubject::detach`] is called or
+    /// until every attachment of it has been
+    /// undone.
+    pub fn subscribe(
+        &self,
+        attach_observer: O,
+    ) -> Subscription
+    {
+        self.listeners.subscribe(attach_observer)
     }
 }
 
@@ -88,7 +129,7 @@ where
         attach_observer: O,
     )
     {
-        self.listeners.borrow_mut().insert(attach_observer);
+        self.listeners.attach(attach_observer);
     }
 }
 
@@ -109,7 +150,12 @@ where
         detach_observer: &O,
     ) -> Result<(), DetachError>
     {
-        if self.listeners.remove(detach_observer) {
+        if self
+            .listeners
+            .storage_mut()
+            .remove(detach_observer)
+            .is_some()
+        {
             Ok(())
         } else {
             Err(DetachError::ObserverNotFound)
@@ -123,6 +169,7 @@ where
 {
     fn notify(&self)
     {
-        self.listeners.iter().for_each(Observer::notify);
+        self.listeners
+            .dispatch(&mut |_, listener| listener.notify());
     }
 }
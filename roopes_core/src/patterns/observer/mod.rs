@@ -14,11 +14,17 @@
 //! ![observer diagram][observer-diagram]
 
 pub mod hash_subject;
+pub mod sync_hash_subject;
+pub mod sync_vec_subject;
 pub mod vec_subject;
+pub mod weak_subject;
 
 use crate::prelude::*;
 pub use hash_subject::HashSubject;
+pub use sync_hash_subject::SyncHashSubject;
+pub use sync_vec_subject::SyncVecSubject;
 pub use vec_subject::VecSubject;
+pub use weak_subject::WeakSubject;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,138 @@
+//! Contains types which allow [`Observer`]s
+//! (which implement the appropriate [`Hash`] and
+//! [`Eq`] traits) to be removed dynamically from
+//! a [`Subject`] shared between threads.
+
+use super::{
+    hash_subject::{
+        DetachError,
+        HashSetObserver,
+    },
+    AttachableSubject,
+    DetachableSubject,
+    Observer,
+    Subject,
+};
+use std::{
+    collections::HashSet,
+    sync::{
+        Arc,
+        RwLock,
+    },
+};
+
+/// Implements [`Subject`] backed by a
+/// [`HashSet<T>`] behind an [`Arc`]ed
+/// [`RwLock`]. `T` must implement [`PartialEq`]
+/// and [`Hash`], and be [`Send`] and [`Sync`].
+/// Clones of a [`SyncHashSubject`] share the same
+/// listeners.  Listeners are copied out of the
+/// lock before they are notified, so an observer
+/// may attach or detach through a clone from
+/// within [`Subject::notify`].  Such changes take
+/// effect from the next notification.
+pub struct SyncHashSubject<O>
+where
+    O: HashSetObserver + Send + Sync,
+{
+    listeners: Arc<RwLock<HashSet<Arc<O>>>>,
+}
+
+impl<O> Default for SyncHashSubject<O>
+where
+    O: HashSetObserver + Send + Sync,
+{
+    fn default() -> SyncHashSubject<O>
+    {
+        Self::new(HashSet::default())
+    }
+}
+
+impl<O> SyncHashSubject<O>
+where
+    O: HashSetObserver + Send + Sync,
+{
+    /// Creates a new [`SyncHashSubject`] with an
+    /// existing set of listeners.
+    /// [`SyncHashSubject::default`] is probably
+    /// preferable in most situations.
+    #[must_use]
+    pub fn new(listeners: HashSet<O>) -> SyncHashSubject<O>
+    {
+        let listeners = listeners.into_iter().map(Arc::new).collect();
+
+        SyncHashSubject {
+            listeners: Arc::new(RwLock::new(listeners)),
+        }
+    }
+}
+
+impl<O> Clone for SyncHashSubject<O>
+where
+    O: HashSetObserver + Send + Sync,
+{
+    fn clone(&self) -> Self
+    {
+        SyncHashSubject {
+            listeners: self.listeners.clone(),
+        }
+    }
+}
+
+impl<O> AttachableSubject<O> for SyncHashSubject<O>
+where
+    O: HashSetObserver + Send + Sync,
+{
+    fn attach(
+        &mut self,
+        attach_observer: O,
+    )
+    {
+        self.listeners
+            .write()
+            .expect("SyncHashSubject lock was poisoned.")
+            .insert(Arc::new(attach_observer));
+    }
+}
+
+impl<O> DetachableSubject<O, DetachError> for SyncHashSubject<O>
+where
+    O: HashSetObserver + Send + Sync,
+{
+    fn detach(
+        &mut self,
+        detach_observer: &O,
+    ) -> Result<(), DetachError>
+    {
+        if self
+            .listeners
+            .write()
+            .expect("SyncHashSubject lock was poisoned.")
+            .remove(detach_observer)
+        {
+            Ok(())
+        } else {
+            Err(DetachError::ObserverNotFound)
+        }
+    }
+}
+
+impl<O> Subject for SyncHashSubject<O>
+where
+    O: HashSetObserver + Send + Sync,
+{
+    fn notify(&self)
+    {
+        let listeners: Vec<_> = self
+            .listeners
+            .read()
+            .expect("SyncHashSubject lock was poisoned.")
+            .iter()
+            .cloned()
+            .collect();
+
+        for listener in listeners {
+            listener.notify();
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Contains types which implement the Observer
+//! pattern via an internal [`Vec`] which may be
+//! shared between threads.
+
+use super::{
+    vec_subject::DetachError,
+    AttachableSubject,
+    DetachableSubject,
+    Observer,
+    Subject,
+};
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+/// Implements [`Subject`] backed by a [`Vec<T>`]
+/// behind an [`Arc`]ed [`RwLock`].  Clones of a
+/// [`SyncVecSubject`] share the same listeners,
+/// so a clone can be handed to each worker thread.
+/// Listeners are copied out of the lock before
+/// they are notified, so an observer may attach or
+/// detach through a clone from within
+/// [`Subject::notify`].  Such changes take effect
+/// from the next notification.
+/// If `T` implements [`Eq`], then
+/// [`DetachableSubject`] is also provided.
+/// [`AttachableSubject`] is always provided.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::sync::{
+///     atomic::{
+///         AtomicBool,
+///         Ordering,
+///     },
+///     Arc,
+/// };
+///
+/// let mut vs = observer::SyncVecSubject::default();
+///
+/// let has_run = Arc::new(AtomicBool::new(false));
+/// {
+///     let has_run = has_run.clone();
+///
+///     let lc: ObservingCommand<_> = command::SyncHeap::from(move || {
+///         has_run.store(true, Ordering::SeqCst);
+///     })
+///     .into();
+///
+///     vs.attach(lc);
+/// }
+///
+/// let worker_vs = vs.clone();
+/// std::thread::spawn(move || worker_vs.notify())
+///     .join()
+///     .unwrap();
+/// assert!(has_run.load(Ordering::SeqCst));
+/// ```
+pub struct SyncVecSubject<O>
+where
+    O: Observer + Send + Sync,
+{
+    listeners: Arc<RwLock<Vec<Arc<O>>>>,
+}
+
+impl<O> SyncVecSubject<O>
+where
+    O: Observer + Send + Sync,
+{
+    /// Creates a new [`SyncVecSubject`] with an
+    /// existing list of listeners.
+    /// [`SyncVecSubject::default`] is probably
+    /// preferable in most circumstances.
+    #[must_use]
+    pub fn new(listeners: Vec<O>) -> SyncVecSubject<O>
+    {
+        let listeners = listeners.into_iter().map(Arc::new).collect();
+
+        SyncVecSubject {
+            listeners: Arc::new(RwLock::new(listeners)),
+        }
+    }
+}
+
+impl<O> Clone for SyncVecSubject<O>
+where
+    O: Observer + Send + Sync,
+{
+    fn clone(&self) -> Self
+    {
+        SyncVecSubject {
+            listeners: self.listeners.clone(),
+        }
+    }
+}
+
+impl<O> AttachableSubject<O> for SyncVecSubject<O>
+where
+    O: Observer + Send + Sync,
+{
+    fn attach(
+        &mut self,
+        attach_observer: O,
+    )
+    {
+        self.listeners
+            .write()
+            .expect("SyncVecSubject lock was poisoned.")
+            .push(Arc::new(attach_observer));
+    }
+}
+
+impl<O> DetachableSubject<O, DetachError> for SyncVecSubject<O>
+where
+    O: Observer + Send + Sync + Eq,
+{
+    fn detach(
+        &mut self,
+        detach_observer: &O,
+    ) -> Result<(), DetachError>
+    {
+        let mut listeners = self
+            .listeners
+            .write()
+            .expect("SyncVecSubject lock was poisoned.");
+
+        let i = listeners
+            .iter()
+            .position(|o| o.as_ref().eq(detach_observer))
+            .ok_or(DetachError::ObserverNotFound)?;
+
+        listeners.remove(i);
+
+        Ok(())
+    }
+}
+
+impl<O> Default for SyncVecSubject<O>
+where
+    O: Observer + Send + Sync,
+{
+    fn default() -> Self
+    {
+        Self::new(Vec::default())
+    }
+}
+
+impl<O> Subject for SyncVecSubject<O>
+where
+    O: Observer + Send + Sync,
+{
+    fn notify(&self)
+    {
+        let listeners = self
+            .listeners
+            .read()
+            .expect("SyncVecSubject lock was poisoned.")
+            .clone();
+
+        for listener in listeners {
+            listener.notify();
+        }
+    }
+}
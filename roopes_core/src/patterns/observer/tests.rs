@@ -8,6 +8,7 @@ use std::{
     rc::Rc,
 };
 #[derive(Hash, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
 enum TestCommands
 {
     HasRun,
@@ -49,7 +50,6 @@ fn toggle_hashset_subject_notify()
             let tgl = *has_run_toggle.borrow();
             (*has_run_toggle.borrow_mut()) = !tgl;
         }
-        .into()
     }));
 
     let hc: ObservingCommand<_> =
@@ -91,8 +91,8 @@ fn observing_command_equality()
 #[test]
 fn toggle_subject_inequality()
 {
-    let lambda_command = command::Heap::from(|| {}.into());
-    let lambda_command2 = command::Heap::from(|| {}.into());
+    let lambda_command = command::Heap::from(|| {});
+    let lambda_command2 = command::Heap::from(|| {});
 
     let heap_command = command::Heap::new(Box::new(lambda_command));
     let heap_command2 = command::Heap::new(Box::new(lambda_command2));
@@ -279,3 +279,499 @@ fn multiple_vector_subject_notify()
     assert!((*has_run_1.borrow()));
     assert!((*has_run_2.borrow()));
 }
+
+#[test]
+fn sync_vector_subject_notify_across_threads()
+{
+    use std::sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    };
+
+    let mut vs = observer::SyncVecSubject::default();
+
+    let run_ct = Arc::new(AtomicUsize::new(0));
+
+    let lc: ObservingCommand<_> = command::SyncHeap::from(enclose!(
+        (run_ct) move || {
+            run_ct.fetch_add(1, Ordering::SeqCst);
+        }
+    ))
+    .into();
+
+    vs.attach(lc);
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let vs = vs.clone();
+            std::thread::spawn(move || vs.notify())
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(4, run_ct.load(Ordering::SeqCst));
+}
+
+#[test]
+fn sync_hashset_subject_detach()
+{
+    use std::sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    };
+
+    let mut hs = observer::SyncHashSubject::default();
+
+    let has_run_1 = Arc::new(AtomicBool::new(false));
+    let has_run_2 = Arc::new(AtomicBool::new(false));
+
+    let command = |has_run: &Arc<AtomicBool>, id| {
+        let has_run = has_run.clone();
+        let command = command::SyncHeap::from(move || {
+            has_run.store(true, Ordering::SeqCst);
+        });
+
+        ObservingCommand::new(command::Hashable::new(command, id))
+    };
+
+    hs.attach(command(&has_run_1, TestCommands::HasRun));
+    hs.attach(command(&has_run_2, TestCommands::HasRunTwo));
+
+    hs.detach(&command(&has_run_2, TestCommands::HasRunTwo))
+        .unwrap();
+
+    let worker_hs = hs.clone();
+    std::thread::spawn(move || worker_hs.notify())
+        .join()
+        .unwrap();
+
+    assert!(has_run_1.load(Ordering::SeqCst));
+    assert!(!has_run_2.load(Ordering::SeqCst));
+}
+
+#[test]
+fn vec_subject_subscription_detaches_on_drop()
+{
+    let count = Rc::new(RefCell::new(0));
+    let vs = observer::VecSubject::default();
+
+    // Heap commands don't implement `Eq`.
+    let counter = |amount| {
+        let count = count.clone();
+        ObservingCommand::new(command::Heap::from(move || {
+            *count.borrow_mut() += amount;
+        }))
+    };
+
+    let ones = vs.subscribe(counter(1));
+    let tens = vs.subscribe(counter(10));
+    vs.notify();
+    assert_eq!(11, *count.borrow());
+
+    drop(ones);
+    vs.notify();
+    assert_eq!(21, *count.borrow());
+
+    tens.detach();
+    vs.notify();
+    assert_eq!(21, *count.borrow());
+}
+
+#[test]
+fn vec_subject_subscription_forget()
+{
+    let count = Rc::new(RefCell::new(0));
+    let vs = observer::VecSubject::default();
+
+    vs.subscribe(ObservingCommand::new(command::Heap::from(
+        enclose!((count) move || *count.borrow_mut() += 1),
+    )))
+    .forget();
+    vs.notify();
+
+    assert_eq!(1, *count.borrow());
+}
+
+#[test]
+fn subscription_outlives_subject()
+{
+    let vs = observer::VecSubject::default();
+    let subscription =
+        vs.subscribe(ObservingCommand::new(command::Heap::from(|| {})));
+
+    drop(vs);
+    drop(subscription);
+}
+
+#[test]
+fn hash_subject_subscription_detaches_on_drop()
+{
+    let has_run = Rc::new(RefCell::new(false));
+    let hs = HashSubject::default();
+
+    let subscription =
+        hs.subscribe(ObservingCommand::new(command::Hashable::new(
+            command::Heap::from(enclose!((has_run) move || {
+                *has_run.borrow_mut() = true;
+            })),
+            TestCommands::HasRun,
+        )));
+    drop(subscription);
+    hs.notify();
+
+    assert!(!*has_run.borrow());
+}
+
+type HeapObserver = ObservingCommand<command::Heap>;
+
+fn observe<F>(f: F) -> HeapObserver
+where
+    F: Fn() + 'static,
+{
+    ObservingCommand::new(command::Heap::from(f))
+}
+
+#[test]
+fn vec_subject_subscribe_during_notify()
+{
+    let subject = Rc::new(observer::VecSubject::<HeapObserver>::default());
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let subscriptions = Rc::new(RefCell::new(Vec::new()));
+
+    let weak_subject = Rc::downgrade(&subject);
+    subscriptions
+        .borrow_mut()
+        .push(subject.subscribe(observe(enclose!(
+            (log, subscriptions) move || {
+                log.borrow_mut().push("outer");
+                let subject = weak_subject.upgrade().unwrap();
+                let inner = subject.subscribe(observe(enclose!((log) move || {
+                    log.borrow_mut().push("inner");
+                })));
+                subscriptions.borrow_mut().push(inner);
+            }
+        ))));
+
+    subject.notify();
+    assert_eq!(vec!["outer"], *log.borrow());
+
+    subject.notify();
+    assert_eq!(vec!["outer", "outer", "inner"], *log.borrow());
+
+    subscriptions.borrow_mut().clear();
+}
+
+#[test]
+fn vec_subject_unsubscribe_during_notify()
+{
+    let subject = Rc::new(observer::VecSubject::<HeapObserver>::default());
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let later = Rc::new(RefCell::new(None));
+
+    let first = subject.subscribe(observe(enclose!((log, later) move || {
+        log.borrow_mut().push("first");
+        later.borrow_mut().take();
+    })));
+    *later.borrow_mut() =
+        Some(subject.subscribe(observe(enclose!((log) move || {
+            log.borrow_mut().push("later");
+        }))));
+
+    subject.notify();
+    subject.notify();
+
+    assert_eq!(vec!["first", "first"], *log.borrow());
+    drop(first);
+}
+
+#[test]
+fn vec_subject_unsubscribe_self_during_notify()
+{
+    let subject = Rc::new(observer::VecSubject::<HeapObserver>::default());
+    let count = Rc::new(RefCell::new(0));
+    let own = Rc::new(RefCell::new(None));
+
+    *own.borrow_mut() = Some(subject.subscribe(observe(enclose!(
+        (count, own) move || {
+            *count.borrow_mut() += 1;
+            own.borrow_mut().take();
+        }
+    ))));
+
+    subject.notify();
+    subject.notify();
+
+    assert_eq!(1, *count.borrow());
+}
+
+#[test]
+fn vec_subject_nested_notify()
+{
+    let subject = Rc::new(observer::VecSubject::<HeapObserver>::default());
+    let depth = Rc::new(RefCell::new(0));
+
+    let weak_subject = Rc::downgrade(&subject);
+    subject
+        .subscribe(observe(enclose!((depth) move || {
+            *depth.borrow_mut() += 1;
+            if *depth.borrow() < 3 {
+                weak_subject.upgrade().unwrap().notify();
+            }
+        })))
+        .forget();
+
+    subject.notify();
+
+    assert_eq!(3, *depth.borrow());
+}
+
+#[test]
+fn hash_subject_unsubscribe_during_notify()
+{
+    let subject = Rc::new(HashSubject::default());
+    let count = Rc::new(RefCell::new(0));
+    let own = Rc::new(RefCell::new(None));
+
+    *own.borrow_mut() = Some(subject.subscribe(ObservingCommand::new(
+        command::Hashable::new(
+            command::Heap::from(enclose!((count, own) move || {
+                *count.borrow_mut() += 1;
+                own.borrow_mut().take();
+            })),
+            TestCommands::HasRun,
+        ),
+    )));
+
+    subject.notify();
+    subject.notify();
+
+    assert_eq!(1, *count.borrow());
+}
+
+#[test]
+fn weak_subject_prunes_dropped_observers()
+{
+    let count = Rc::new(RefCell::new(0));
+    let subject = observer::WeakSubject::new();
+
+    let observers: Vec<_> = (0..3)
+        .map(|_| {
+            Rc::new(observe(enclose!((count) move || *count.borrow_mut() += 1)))
+        })
+        .collect();
+    for observer in &observers {
+        subject.attach(observer);
+    }
+
+    subject.notify();
+    assert_eq!(3, *count.borrow());
+
+    let mut observers = observers.into_iter();
+    let kept = observers.next().unwrap();
+    drop(observers);
+
+    assert_eq!(3, subject.len());
+    assert_eq!(2, subject.notify_and_prune());
+    assert_eq!(4, *count.borrow());
+    assert_eq!(1, subject.len());
+
+    drop(kept);
+    assert_eq!(1, subject.prune());
+    assert!(subject.is_empty());
+}
+
+#[test]
+fn weak_subject_detach_by_identity()
+{
+    let count = Rc::new(RefCell::new(0));
+    let subject = observer::WeakSubject::default();
+
+    let observer =
+        Rc::new(observe(enclose!((count) move || *count.borrow_mut() += 1)));
+    subject.attach(&observer);
+    subject.detach(&observer).unwrap();
+    assert!(subject.detach(&observer).is_err());
+
+    subject.notify();
+    assert_eq!(0, *count.borrow());
+}
+
+#[test]
+fn weak_subject_detach_during_notify()
+{
+    let count = Rc::new(RefCell::new(0));
+    let results = Rc::new(RefCell::new(Vec::new()));
+    let subject = Rc::new(observer::WeakSubject::new());
+
+    let counter =
+        Rc::new(observe(enclose!((count) move || *count.borrow_mut() += 1)));
+    let detacher =
+        Rc::new(observe(enclose!((subject, counter, results) move || {
+            let first = subject.detach(&counter).is_ok();
+            let second = subject.detach(&counter).is_ok();
+            results.borrow_mut().push((first, second, subject.len()));
+        })));
+    subject.attach(&detacher);
+    subject.attach(&counter);
+
+    subject.notify();
+
+    assert_eq!(0, *count.borrow());
+    assert_eq!(vec![(true, false, 2)], *results.borrow());
+    assert_eq!(1, subject.len());
+}
+
+#[test]
+fn hash_subject_notifies_observer_still_subscribed_during_notify()
+{
+    let subject = HashSubject::default();
+    let count = Rc::new(RefCell::new(0));
+    let doomed = Rc::new(RefCell::new(None));
+
+    let counter = || {
+        ObservingCommand::new(command::Hashable::new(
+            command::Heap::from(enclose!((count) move || {
+                *count.borrow_mut() += 1;
+            })),
+            TestCommands::HasRun,
+        ))
+    };
+
+    // The same observer, subscribed twice, stays
+    // attached while either subscription lives.
+    *doomed.borrow_mut() = Some(subject.subscribe(counter()));
+    let kept = subject.subscribe(counter());
+    let dropper =
+        subject.subscribe(ObservingCommand::new(command::Hashable::new(
+            command::Heap::from(enclose!((doomed) move || {
+                doomed.borrow_mut().take();
+            })),
+            TestCommands::HasRunTwo,
+        )));
+
+    subject.notify();
+    assert_eq!(1, *count.borrow());
+
+    subject.notify();
+    assert_eq!(2, *count.borrow());
+
+    drop((kept, dropper));
+}
+
+type SyncObserver =
+    ObservingCommand<command::Hashable<command::SyncHeap, &'static str>>;
+
+fn sync_observe<F>(
+    id: &'static str,
+    f: F,
+) -> SyncObserver
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    ObservingCommand::new(command::Hashable::new(
+        command::SyncHeap::from(f),
+        id,
+    ))
+}
+
+fn attach_and_detach_during_notify<S, E>(mut subject: S)
+where
+    S: Subject
+        + AttachableSubject<SyncObserver>
+        + DetachableSubject<SyncObserver, E>
+        + Clone
+        + Send
+        + 'static,
+    E: std::fmt::Debug,
+{
+    use std::sync::{
+        Arc,
+        Mutex,
+    };
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let subject_ext = Mutex::new(subject.clone());
+
+    subject.attach(sync_observe(
+        "first",
+        enclose!((log) move || {
+            log.lock().unwrap().push("first");
+
+            let mut subject = subject_ext.lock().unwrap();
+            subject.attach(sync_observe(
+                "later",
+                enclose!((log) move || log.lock().unwrap().push("later")),
+            ));
+            subject.detach(&sync_observe("first", || {})).unwrap();
+        }),
+    ));
+
+    subject.notify();
+    assert_eq!(vec!["first"], *log.lock().unwrap());
+
+    subject.notify();
+    assert_eq!(vec!["first", "later"], *log.lock().unwrap());
+}
+
+#[test]
+fn sync_vector_subject_attach_and_detach_during_notify()
+{
+    attach_and_detach_during_notify(observer::SyncVecSubject::default());
+}
+
+#[test]
+fn sync_hashset_subject_attach_and_detach_during_notify()
+{
+    attach_and_detach_during_notify(observer::SyncHashSubject::default());
+}
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn single_threaded_subjects_are_send_with_send_observers()
+{
+    type SyncObserver = ObservingCommand<command::SyncHeap>;
+    type SyncHashObserver =
+        ObservingCommand<command::Hashable<command::SyncHeap, &'static str>>;
+
+    assert_send::<observer::VecSubject<SyncObserver>>();
+    assert_send::<observer::HashSubject<SyncHashObserver>>();
+}
+
+#[test]
+fn subscribed_vector_subject_moves_between_threads()
+{
+    use std::sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    };
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let observe = || {
+        ObservingCommand::new(command::SyncHeap::from(
+            enclose!((count) move || {
+                count.fetch_add(1, Ordering::SeqCst);
+            }),
+        ))
+    };
+
+    let subject = observer::VecSubject::default();
+    let _kept = subject.subscribe(observe());
+    drop(subject.subscribe(observe()));
+
+    std::thread::spawn(move || subject.notify()).join().unwrap();
+
+    assert_eq!(1, count.load(Ordering::SeqCst));
+}
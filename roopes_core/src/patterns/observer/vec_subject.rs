@@ -7,15 +7,28 @@ use super::{
     Observer,
     Subject,
 };
-use std::{
-    borrow::BorrowMut,
-    cell::RefCell,
+use crate::patterns::subscription::{
+    registry::Registry,
+    Subscription,
 };
+use std::cell::RefCell;
 
 /// Implements [`Subject`] backed by a [`Vec<T>`].
 /// If `T` implements [`Eq`],
 /// then [`DetachableSubject`] is also provided.
-/// [`AttachableSubject`] is always provided.
+/// [`AttachableSubject`] is always provided, and
+/// [`VecSubject::subscribe`] attaches any
+/// observer for the lifetime of a
+/// [`Subscription`].
+///
+/// Observers may subscribe, or drop their
+/// [`Subscription`]s, from within
+/// [`Subject::notify`].  Observers subscribed
+/// during a notification first receive the next
+/// one; observers unsubscribed during a
+/// notification receive nothing further,
+/// including the rest of the notification in
+/// progress.
 ///
 ///  # Examples
 ///  ``` rust
@@ -44,12 +57,11 @@ use std::{
 /// vs.notify();
 /// assert!((*has_run.borrow()));
 ///  ```
-
 pub struct VecSubject<O>
 where
     O: Observer,
 {
-    listeners: RefCell<Vec<O>>,
+    listeners: Registry<Vec<(usize, O)>>,
 }
 
 impl<O> VecSubject<O>
@@ -63,7 +75,25 @@ where
     #[must_use]
     pub fn new(listeners: RefCell<Vec<O>>) -> VecSubject<O>
     {
-        VecSubject { listeners }
+        let listeners: Vec<_> =
+            listeners.into_inner().into_iter().enumerate().collect();
+        let next_id = listeners.len();
+
+        VecSubject {
+            listeners: Registry::new(listeners, next_id),
+        }
+    }
+
+    /// Attaches the given [`Observer`] until the
+    /// returned [`Subscription`] is dropped.
+    /// Unlike [`DetachableSubject::detach`], the
+    /// observer needn't implement [`Eq`].
+    pub fn subscribe(
+        &self,
+        attach_observer: O,
+    ) -> Subscription
+    {
+        self.listeners.subscribe(attach_observer)
     }
 }
 
@@ -76,7 +106,7 @@ where
         attach_observer: O,
     )
     {
-        self.listeners.borrow_mut().push(attach_observer);
+        self.listeners.attach(attach_observer);
     }
 }
 
@@ -96,15 +126,13 @@ where
         detach_observer: &O,
     ) -> Result<(), DetachError>
     {
-        let (i, _) = self
-            .listeners
-            .borrow()
+        let mut listeners = self.listeners.storage_mut();
+        let i = listeners
             .iter()
-            .enumerate()
-            .find(|(_, o)| o.eq(&detach_observer))
+            .position(|(_, o)| o.eq(detach_observer))
             .ok_or(DetachError::ObserverNotFound)?;
 
-        self.listeners.borrow_mut().swap_remove(i);
+        listeners.remove(i);
 
         Ok(())
     }
@@ -126,6 +154,7 @@ where
 {
     fn notify(&self)
     {
-        self.listeners.borrow().iter().for_each(Observer::notify);
+        self.listeners
+            .dispatch(&mut |_, listener| listener.notify());
     }
 }
@@ -0,0 +1,183 @@
+//! Contains a [`Subject`] which holds its
+//! [`Observer`]s weakly, forgetting them once
+//! they have been dropped.
+
+use super::{
+    vec_subject::DetachError,
+    Observer,
+    Subject,
+};
+use crate::patterns::subscription::registry::Registry;
+use std::rc::{
+    Rc,
+    Weak,
+};
+
+type WeakObservers = Vec<(usize, Weak<dyn Observer>)>;
+
+/// Implements [`Subject`] backed by a [`Vec`] of
+/// [`Weak`] references to [`Observer`]s.  The
+/// [`WeakSubject`] never keeps an observer alive:
+/// observers which have been dropped are skipped,
+/// and pruned, on the next notification.  This
+/// avoids both manual detachment and [`Rc`]
+/// cycles between observers and the subjects
+/// they are attached to.
+///
+/// As with [`super::VecSubject`], observers may be
+/// attached or detached from within
+/// [`Subject::notify`].
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// let count = Rc::new(RefCell::new(0));
+/// let subject = observer::WeakSubject::default();
+///
+/// let observer = Rc::new(ObservingCommand::new(command::Heap::from({
+///     let count = count.clone();
+///     move || *count.borrow_mut() += 1
+/// })));
+/// subject.attach(&observer);
+///
+/// assert_eq!(0, subject.notify_and_prune());
+/// drop(observer);
+/// assert_eq!(1, subject.notify_and_prune());
+/// assert_eq!(1, *count.borrow());
+/// assert!(subject.is_empty());
+/// ```
+pub struct WeakSubject
+{
+    listeners: Registry<WeakObservers>,
+}
+
+impl WeakSubject
+{
+    /// Creates a new [`WeakSubject`] with no
+    /// observers.
+    #[must_use]
+    pub fn new() -> WeakSubject
+    {
+        WeakSubject {
+            listeners: Registry::new(Vec::new(), 0),
+        }
+    }
+
+    /// Attaches a weak reference to the given
+    /// [`Observer`], which is notified for as long
+    /// as it is alive.
+    pub fn attach<O>(
+        &self,
+        attach_observer: &Rc<O>,
+    ) where
+        O: Observer + 'static,
+    {
+        let observer: Weak<dyn Observer> =
+            Rc::downgrade(attach_observer) as Weak<_>;
+
+        self.listeners.attach(observer);
+    }
+
+    /// Detaches the given [`Observer`], compared
+    /// by identity rather than [`Eq`].
+    ///
+    /// # Errors
+    /// [`DetachError::ObserverNotFound`]: The
+    /// observer was not attached.
+    pub fn detach<O>(
+        &self,
+        detach_observer: &Rc<O>,
+    ) -> Result<(), DetachError>
+    where
+        O: Observer + 'static,
+    {
+        let observer: Weak<dyn Observer> =
+            Rc::downgrade(detach_observer) as Weak<_>;
+        let id = *self
+            .listeners
+            .find(&mut |listener| Weak::ptr_eq(listener, &observer))
+            .first()
+            .ok_or(DetachError::ObserverNotFound)?;
+
+        self.listeners.detach(id);
+
+        Ok(())
+    }
+
+    /// Notifies every live [`Observer`], giving
+    /// back the number of dropped observers which
+    /// were pruned.
+    pub fn notify_and_prune(&self) -> usize
+    {
+        let mut dead = Vec::new();
+
+        self.listeners.dispatch(&mut |id, listener| {
+            match listener.upgrade() {
+                | Some(listener) => listener.notify(),
+                | None => dead.push(id),
+            }
+        });
+
+        self.remove(&dead)
+    }
+
+    /// Prunes dropped [`Observer`]s without
+    /// notifying, giving back the number pruned.
+    pub fn prune(&self) -> usize
+    {
+        let dead = self
+            .listeners
+            .find(&mut |listener| listener.strong_count() == 0);
+
+        self.remove(&dead)
+    }
+
+    /// Gives the number of attached observers,
+    /// including dropped ones not yet pruned.
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        self.listeners.len()
+    }
+
+    /// Indicates whether no observers are
+    /// attached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    fn remove(
+        &self,
+        ids: &[usize],
+    ) -> usize
+    {
+        for id in ids {
+            self.listeners.detach(*id);
+        }
+
+        ids.len()
+    }
+}
+
+impl Default for WeakSubject
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl Subject for WeakSubject
+{
+    fn notify(&self)
+    {
+        self.notify_and_prune();
+    }
+}
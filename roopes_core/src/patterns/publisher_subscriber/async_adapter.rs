@@ -0,0 +1,59 @@
+//! Provides an [`AsyncPublisher`] which wraps a
+//! synchronous [`Publisher`].
+
+use super::{
+    AsyncPublisher,
+    Publisher,
+};
+use crate::primitives::asynchronous::BoxFuture;
+use std::marker::PhantomData;
+
+/// Adapts a [`Publisher`] into an
+/// [`AsyncPublisher`].  The delegate publishes
+/// when the returned future is first polled, and
+/// the future completes immediately afterwards.
+pub struct AsyncAdapter<P, M>
+where
+    P: Publisher<M>,
+{
+    delegate: P,
+    _retain_types: PhantomData<M>,
+}
+
+impl<P, M> AsyncAdapter<P, M>
+where
+    P: Publisher<M>,
+{
+    /// Creates an [`AsyncAdapter`] from a given
+    /// [`Publisher`].
+    pub fn new(delegate: P) -> AsyncAdapter<P, M>
+    {
+        AsyncAdapter {
+            delegate,
+            _retain_types: PhantomData,
+        }
+    }
+}
+
+impl<P, M> AsyncPublisher<M> for AsyncAdapter<P, M>
+where
+    P: Publisher<M>,
+{
+    fn publish_async<'a>(
+        &'a self,
+        message: &'a M,
+    ) -> BoxFuture<'a, ()>
+    {
+        Box::pin(async move { self.delegate.publish(message) })
+    }
+}
+
+impl<P, M> From<P> for AsyncAdapter<P, M>
+where
+    P: Publisher<M>,
+{
+    fn from(delegate: P) -> Self
+    {
+        AsyncAdapter::new(delegate)
+    }
+}
@@ -0,0 +1,124 @@
+//! Contains an implementation of
+//! [`AsyncPublisher`] which stores its
+//! subscribers, as [`AsyncHandler`]s, in a
+//! [`Vec`].
+
+use super::{
+    vec_publisher::DetachError,
+    AsyncPublisher,
+};
+use crate::{
+    prelude::*,
+    primitives::asynchronous::BoxFuture,
+};
+use core::marker::PhantomData;
+use std::fmt::Debug;
+
+/// Implements an [`AsyncPublisher`] based on a
+/// [`Vec`] of [`AsyncHandler`]s.  Each subscriber
+/// is awaited in turn, in attachment order, so a
+/// subscriber only receives the message once the
+/// previous one has finished with it.
+pub struct AsyncVecPublisher<M, S>
+where
+    S: AsyncHandler<M>,
+{
+    listeners: Vec<S>,
+    _retain_types: PhantomData<M>,
+}
+
+impl<M, S> Debug for AsyncVecPublisher<M, S>
+where
+    S: AsyncHandler<M> + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("AsyncVecPublisher")
+            .field("listeners", &self.listeners)
+            .finish()
+    }
+}
+
+impl<M, S> Default for AsyncVecPublisher<M, S>
+where
+    S: AsyncHandler<M>,
+{
+    fn default() -> Self
+    {
+        Self::new(Vec::default())
+    }
+}
+
+impl<M, S> AsyncVecPublisher<M, S>
+where
+    S: AsyncHandler<M>,
+{
+    /// Creates a new [`AsyncVecPublisher`] with
+    /// the given [`Vec`] of starting
+    /// subscribers.
+    #[must_use]
+    pub fn new(listeners: Vec<S>) -> AsyncVecPublisher<M, S>
+    {
+        AsyncVecPublisher {
+            listeners,
+            _retain_types: PhantomData,
+        }
+    }
+
+    /// Attaches the given subscriber so it would
+    /// now receive messages.
+    pub fn attach(
+        &mut self,
+        attach_subscriber: S,
+    )
+    {
+        self.listeners.push(attach_subscriber);
+    }
+}
+
+impl<M, S> AsyncVecPublisher<M, S>
+where
+    S: AsyncHandler<M> + Eq,
+{
+    /// Detaches the given subscriber so it would
+    /// no longer receive messages.
+    ///
+    /// # Errors
+    /// [`DetachError::SubscriberNotFound`]: The
+    /// subscriber was not attached.
+    pub fn detach(
+        &mut self,
+        detach_subscriber: &S,
+    ) -> Result<(), DetachError>
+    {
+        let i = self
+            .listeners
+            .iter()
+            .position(|o| o.eq(detach_subscriber))
+            .ok_or(DetachError::SubscriberNotFound)?;
+
+        self.listeners.remove(i);
+
+        Ok(())
+    }
+}
+
+impl<M, S> AsyncPublisher<M> for AsyncVecPublisher<M, S>
+where
+    S: AsyncHandler<M>,
+{
+    fn publish_async<'a>(
+        &'a self,
+        message: &'a M,
+    ) -> BoxFuture<'a, ()>
+    {
+        Box::pin(async move {
+            for listener in &self.listeners {
+                listener.handle_async(message).await;
+            }
+        })
+    }
+}
@@ -0,0 +1,321 @@
+//! Contains a [`ChannelPublisher`], which sends
+//! messages over a channel to subscribers running
+//! on a dedicated dispatcher thread.
+
+use crate::prelude::*;
+use std::{
+    fmt::Debug,
+    sync::mpsc::{
+        self,
+        Receiver,
+        SendError,
+        Sender,
+        SyncSender,
+        TrySendError,
+    },
+    thread::{
+        self,
+        JoinHandle,
+    },
+};
+
+enum Envelope<M>
+{
+    Message(M),
+    Shutdown,
+}
+
+enum Channel<M>
+{
+    Unbounded(Sender<Envelope<M>>),
+    Bounded(SyncSender<Envelope<M>>),
+}
+
+impl<M> Channel<M>
+{
+    fn send(
+        &self,
+        envelope: Envelope<M>,
+    ) -> Result<(), SendError<Envelope<M>>>
+    {
+        match self {
+            | Channel::Unbounded(sender) => sender.send(envelope),
+            | Channel::Bounded(sender) => sender.send(envelope),
+        }
+    }
+
+    fn try_send(
+        &self,
+        envelope: Envelope<M>,
+    ) -> Result<(), TrySendError<Envelope<M>>>
+    {
+        match self {
+            | Channel::Unbounded(sender) => {
+                sender.send(envelope).map_err(|SendError(envelope)| {
+                    TrySendError::Disconnected(envelope)
+                })
+            }
+            | Channel::Bounded(sender) => sender.try_send(envelope),
+        }
+    }
+}
+
+/// Implements a [`Publisher`] which sends owned
+/// messages over a [`std::sync::mpsc`] channel to
+/// a dispatcher thread, where they are published
+/// in order to a [`super::VecPublisher`], or any
+/// other [`Publisher`], living on that thread.
+/// Because
+/// the hosted publisher is built on the
+/// dispatcher thread, its subscribers need not be
+/// [`Send`].
+///
+/// When created with [`ChannelPublisher::bounded`]
+/// the channel holds at most `bound` undelivered
+/// messages: [`ChannelPublisher::send`] blocks
+/// while it is full, and
+/// [`ChannelPublisher::try_send`] fails instead.
+///
+/// [`ChannelPublisher::shutdown`], or dropping
+/// the [`ChannelPublisher`], delivers every
+/// message already sent before stopping and
+/// joining the dispatcher thread.
+/// [`ChannelPublisher::into_join_handle`] stops
+/// sending without waiting, giving back the
+/// dispatcher thread's [`JoinHandle`].
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::sync::mpsc;
+///
+/// let (received_tx, received_rx) = mpsc::channel();
+/// let publisher =
+///     publisher_subscriber::ChannelPublisher::bounded(4, move || {
+///         let mut publisher = publisher_subscriber::VecPublisher::default();
+///         publisher.attach(
+///             SubscribingHandler::new(handler::Lambda::new(
+///                 move |m: &u32| {
+///                     received_tx.send(*m).unwrap();
+///                 },
+///             ))
+///             .into_heap(),
+///         );
+///         publisher
+///     });
+///
+/// publisher.send(1).unwrap();
+/// publisher.publish(&2);
+/// publisher.shutdown().unwrap();
+///
+/// assert_eq!(vec![1, 2], received_rx.iter().collect::<Vec<_>>());
+/// ```
+pub struct ChannelPublisher<M>
+where
+    M: Send + 'static,
+{
+    channel: Channel<M>,
+    dispatcher: Option<JoinHandle<()>>,
+}
+
+impl<M> ChannelPublisher<M>
+where
+    M: Send + 'static,
+{
+    /// Spawns a dispatcher thread which builds its
+    /// [`Publisher`] with `make_publisher`, fed by
+    /// an unbounded channel.
+    pub fn new<P, F>(make_publisher: F) -> ChannelPublisher<M>
+    where
+        P: Publisher<M>,
+        F: FnOnce() -> P + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+
+        ChannelPublisher {
+            channel: Channel::Unbounded(sender),
+            dispatcher: Some(Self::spawn(receiver, make_publisher)),
+        }
+    }
+
+    /// Spawns a dispatcher thread which builds its
+    /// [`Publisher`] with `make_publisher`, fed by
+    /// a channel holding at most `bound`
+    /// undelivered messages.  A `bound` of `0`
+    /// makes every send wait for the dispatcher.
+    pub fn bounded<P, F>(
+        bound: usize,
+        make_publisher: F,
+    ) -> ChannelPublisher<M>
+    where
+        P: Publisher<M>,
+        F: FnOnce() -> P + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(bound);
+
+        ChannelPublisher {
+            channel: Channel::Bounded(sender),
+            dispatcher: Some(Self::spawn(receiver, make_publisher)),
+        }
+    }
+
+    /// Sends a message to the dispatcher thread,
+    /// blocking while a bounded channel is full.
+    ///
+    /// # Errors
+    /// [`SendError`]: The dispatcher thread has
+    /// stopped, e.g.: because a subscriber
+    /// panicked.  Holds the unsent message.
+    pub fn send(
+        &self,
+        message: M,
+    ) -> Result<(), SendError<M>>
+    {
+        self.channel
+            .send(Envelope::Message(message))
+            .map_err(|SendError(envelope)| SendError(Self::open(envelope)))
+    }
+
+    /// Sends a message to the dispatcher thread
+    /// without blocking.
+    ///
+    /// # Errors
+    /// [`TrySendError::Full`]: A bounded channel
+    /// is full.
+    ///
+    /// [`TrySendError::Disconnected`]: The
+    /// dispatcher thread has stopped.
+    ///
+    /// Both hold the unsent message.
+    pub fn try_send(
+        &self,
+        message: M,
+    ) -> Result<(), TrySendError<M>>
+    {
+        self.channel
+            .try_send(Envelope::Message(message))
+            .map_err(|error| {
+                match error {
+                    | TrySendError::Full(envelope) => {
+                        TrySendError::Full(Self::open(envelope))
+                    }
+                    | TrySendError::Disconnected(envelope) => {
+                        TrySendError::Disconnected(Self::open(envelope))
+                    }
+                }
+            })
+    }
+
+    /// Delivers every message already sent, then
+    /// stops and joins the dispatcher thread.
+    ///
+    /// # Errors
+    /// The panic payload, if the dispatcher thread
+    /// panicked.
+    pub fn shutdown(mut self) -> thread::Result<()>
+    {
+        self.stop()
+    }
+
+    /// Stops sending messages, giving back the
+    /// dispatcher thread's [`JoinHandle`] without
+    /// waiting for it.  The thread finishes once
+    /// it has delivered every message already
+    /// sent.
+    #[must_use]
+    pub fn into_join_handle(mut self) -> JoinHandle<()>
+    {
+        match self.dispatcher.take() {
+            | Some(dispatcher) => dispatcher,
+            | None => {
+                unreachable!("The dispatcher is only taken when stopping.")
+            }
+        }
+    }
+
+    fn stop(&mut self) -> thread::Result<()>
+    {
+        match self.dispatcher.take() {
+            | Some(dispatcher) => {
+                // The dispatcher may already have stopped,
+                // in which case joining reports why.
+                let _ = self.channel.send(Envelope::Shutdown);
+                dispatcher.join()
+            }
+            | None => Ok(()),
+        }
+    }
+
+    fn spawn<P, F>(
+        receiver: Receiver<Envelope<M>>,
+        make_publisher: F,
+    ) -> JoinHandle<()>
+    where
+        P: Publisher<M>,
+        F: FnOnce() -> P + Send + 'static,
+    {
+        thread::spawn(move || {
+            let publisher = make_publisher();
+
+            for envelope in receiver {
+                match envelope {
+                    | Envelope::Message(message) => publisher.publish(&message),
+                    | Envelope::Shutdown => break,
+                }
+            }
+        })
+    }
+
+    fn open(envelope: Envelope<M>) -> M
+    {
+        match envelope {
+            | Envelope::Message(message) => message,
+            | Envelope::Shutdown => {
+                unreachable!("Only messages are sent by callers.")
+            }
+        }
+    }
+}
+
+impl<M> Drop for ChannelPublisher<M>
+where
+    M: Send + 'static,
+{
+    fn drop(&mut self)
+    {
+        let _ = self.stop();
+    }
+}
+
+impl<M> Debug for ChannelPublisher<M>
+where
+    M: Send + 'static,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("ChannelPublisher")
+            .field("bounded", &matches!(self.channel, Channel::Bounded(_)))
+            .field("dispatcher", &self.dispatcher)
+            .finish()
+    }
+}
+
+/// Sends a clone of each published message,
+/// blocking while a bounded channel is full.
+/// Messages are discarded if the dispatcher
+/// thread has stopped.
+impl<M> Publisher<M> for ChannelPublisher<M>
+where
+    M: Clone + Send + 'static,
+{
+    fn publish(
+        &self,
+        message: &M,
+    )
+    {
+        let _ = self.send(message.clone());
+    }
+}
@@ -0,0 +1,191 @@
+//! Contains an [`EventBus`], which dispatches
+//! messages of any type to the [`Subscriber`]s
+//! registered for that type.
+
+use crate::{
+    patterns::subscription::registry::Registry,
+    prelude::*,
+};
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Debug,
+    rc::Rc,
+};
+
+type Subscribers<M> = Registry<Vec<(usize, Box<dyn Subscriber<M>>)>>;
+
+/// Implements [`Publisher`] for every `'static`
+/// message type, delivering each message only to
+/// the [`Subscriber`]s registered for its
+/// concrete type, which is looked up by
+/// [`TypeId`].  A single [`EventBus`] can
+/// therefore replace one publisher per message
+/// type.  Subscribers of a type receive its
+/// messages in attachment order.
+///
+/// As with [`super::VecPublisher`], subscribers
+/// may subscribe, or drop their
+/// [`Subscription`]s, from within
+/// [`Publisher::publish`], including for other
+/// message types.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// struct Clicked(u32);
+/// struct Closed;
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let mut bus = publisher_subscriber::EventBus::new();
+///
+/// let clicks = log.clone();
+/// bus.attach_handler(handler::Lambda::new(move |e: &Clicked| {
+///     clicks.borrow_mut().push(format!("clicked {}", e.0));
+/// }));
+/// let closes = log.clone();
+/// bus.attach(SubscribingHandler::new(handler::Lambda::new(
+///     move |_: &Closed| closes.borrow_mut().push("closed".to_string()),
+/// )));
+///
+/// bus.publish(&Clicked(3));
+/// bus.publish(&Closed);
+/// bus.publish(&"unregistered");
+///
+/// assert_eq!(vec!["clicked 3", "closed"], *log.borrow());
+/// ```
+#[derive(Default)]
+pub struct EventBus
+{
+    subscribers: RefCell<HashMap<TypeId, Rc<dyn Any>>>,
+}
+
+impl EventBus
+{
+    /// Creates a new [`EventBus`] with no
+    /// subscribers.
+    #[must_use]
+    pub fn new() -> EventBus
+    {
+        EventBus::default()
+    }
+
+    /// Attaches the given [`Subscriber`] to
+    /// messages of type `M`.
+    pub fn attach<M, S>(
+        &mut self,
+        attach_subscriber: S,
+    ) where
+        M: 'static,
+        S: Subscriber<M> + 'static,
+    {
+        self.subscribers_of::<M>()
+            .attach(Box::new(attach_subscriber));
+    }
+
+    /// Attaches the given [`Handler`] to messages
+    /// of type `M`, wrapping it in a
+    /// [`SubscribingHandler`].
+    pub fn attach_handler<M, H>(
+        &mut self,
+        attach_handler: H,
+    ) where
+        M: 'static,
+        H: Handler<M> + 'static,
+    {
+        self.attach(SubscribingHandler::new(attach_handler));
+    }
+
+    /// Attaches the given [`Subscriber`] to
+    /// messages of type `M` until the returned
+    /// [`Subscription`] is dropped.
+    pub fn subscribe<M, S>(
+        &self,
+        attach_subscriber: S,
+    ) -> Subscription
+    where
+        M: 'static,
+        S: Subscriber<M> + 'static,
+    {
+        self.subscribers_of::<M>()
+            .subscribe(Box::new(attach_subscriber))
+    }
+
+    /// Gives the number of [`Subscriber`]s
+    /// attached to messages of type `M`.
+    #[must_use]
+    pub fn subscriber_count<M>(&self) -> usize
+    where
+        M: 'static,
+    {
+        self.existing_subscribers_of::<M>()
+            .map_or(0, |subscribers| subscribers.len())
+    }
+
+    fn existing_subscribers_of<M>(&self) -> Option<Rc<Subscribers<M>>>
+    where
+        M: 'static,
+    {
+        let subscribers =
+            self.subscribers.borrow().get(&TypeId::of::<M>())?.clone();
+
+        Some(
+            subscribers.downcast().expect(
+                "EventBus subscribers were stored under the wrong type.",
+            ),
+        )
+    }
+
+    fn subscribers_of<M>(&self) -> Rc<Subscribers<M>>
+    where
+        M: 'static,
+    {
+        self.existing_subscribers_of::<M>().unwrap_or_else(|| {
+            let subscribers = Rc::new(Subscribers::<M>::new(Vec::new(), 0));
+
+            self.subscribers
+                .borrow_mut()
+                .insert(TypeId::of::<M>(), subscribers.clone());
+
+            subscribers
+        })
+    }
+}
+
+impl Debug for EventBus
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("EventBus")
+            .field("message_types", &self.subscribers.borrow().len())
+            .finish()
+    }
+}
+
+impl<M> Publisher<M> for EventBus
+where
+    M: 'static,
+{
+    fn publish(
+        &self,
+        message: &M,
+    )
+    {
+        if let Some(subscribers) = self.existing_subscribers_of::<M>() {
+            subscribers
+                .dispatch(&mut |_, subscriber| subscriber.receive(message));
+        }
+    }
+}
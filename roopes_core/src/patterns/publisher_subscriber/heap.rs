@@ -46,6 +46,19 @@ impl<M> publisher_subscriber::Subscriber<M> for Subscriber<M>
     }
 }
 
+/// Never consumes the messages it receives.
+impl<M> publisher_subscriber::ConsumingSubscriber<M> for Subscriber<M>
+{
+    fn consume(
+        &self,
+        message: &M,
+    ) -> publisher_subscriber::Propagation
+    {
+        publisher_subscriber::Subscriber::receive(self, message);
+        publisher_subscriber::Propagation::Continue
+    }
+}
+
 impl<M> Debug for Subscriber<M>
 {
     fn fmt(
@@ -23,11 +23,35 @@
 //!
 //! ![publisher subscriber diagram][publisher-subscriber-diagram]
 
+pub mod async_adapter;
+pub mod async_vec_publisher;
+pub mod channel_publisher;
+pub mod event_bus;
 pub mod heap;
+pub mod priority_publisher;
+pub mod queued_publisher;
+pub mod sync_heap;
+pub mod sync_vec_publisher;
+pub mod topic_publisher;
+pub mod try_vec_publisher;
 pub mod vec_publisher;
-use crate::prelude::*;
+pub mod weak_publisher;
+use crate::{
+    prelude::*,
+    primitives::asynchronous::BoxFuture,
+};
+pub use async_adapter::AsyncAdapter;
+pub use async_vec_publisher::AsyncVecPublisher;
+pub use channel_publisher::ChannelPublisher;
+pub use event_bus::EventBus;
+pub use priority_publisher::PriorityPublisher;
+pub use queued_publisher::QueuedPublisher;
 use std::rc::Rc;
+pub use sync_vec_publisher::SyncVecPublisher;
+pub use topic_publisher::TopicPublisher;
+pub use try_vec_publisher::TryVecPublisher;
 pub use vec_publisher::VecPublisher;
+pub use weak_publisher::WeakPublisher;
 
 /// A [`Publisher`] distributes messages it
 /// receives to the [`Subscriber`]s
@@ -42,6 +66,43 @@ pub trait Publisher<M>
     );
 }
 
+/// The fallible counterpart of [`Publisher`].
+/// Distributes messages it receives to its
+/// subscribers, reporting any which could not
+/// handle the message.
+pub trait TryPublisher<M>
+{
+    /// The error produced when publication fails.
+    type Error;
+
+    /// Broadcasts a message to the current
+    /// subscribers of this [`TryPublisher`].
+    ///
+    /// # Errors
+    /// [`TryPublisher::Error`]: One or more
+    /// subscribers failed to handle the message.
+    fn try_publish(
+        &self,
+        message: &M,
+    ) -> Result<(), Self::Error>;
+}
+
+/// The asynchronous counterpart of [`Publisher`].
+/// Distributes messages it receives to its
+/// subscribers, completing once they have all
+/// received the message.
+pub trait AsyncPublisher<M>
+{
+    /// Broadcasts a message to the current
+    /// subscribers of this [`AsyncPublisher`],
+    /// giving back a future which completes once
+    /// delivery has finished.
+    fn publish_async<'a>(
+        &'a self,
+        message: &'a M,
+    ) -> BoxFuture<'a, ()>;
+}
+
 /// Allows [`Subscriber`]s to be added to the
 /// implementing [`Publisher`].
 pub trait AttachablePublisher<M, S>: Publisher<M>
@@ -99,17 +160,55 @@ pub trait Subscriber<M>
     );
 }
 
+/// Indicates whether a message delivered to a
+/// [`ConsumingSubscriber`] should continue on to
+/// the subscribers after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Propagation
+{
+    /// Later subscribers receive the message.
+    Continue,
+
+    /// The message was consumed, so later
+    /// subscribers do not receive it.
+    Stop,
+}
+
+/// A subscriber which may consume the messages it
+/// receives, preventing them from reaching later
+/// subscribers.  Attached to a
+/// [`PriorityPublisher`].  [`heap::Subscriber`],
+/// [`sync_heap::Subscriber`] and
+/// [`crate::aggregates::SubscribingHandler`] are
+/// also [`ConsumingSubscriber`]s, which never
+/// consume the messages they receive.
+pub trait ConsumingSubscriber<M>
+{
+    /// Receives a borrowed message, indicating
+    /// whether it should continue on to later
+    /// subscribers.
+    fn consume(
+        &self,
+        message: &M,
+    ) -> Propagation;
+}
+
 /// Exposes the [`Publisher`], [`Subscriber`],
+/// [`ConsumingSubscriber`], [`AsyncPublisher`],
+/// [`TryPublisher`],
 /// [`AttachablePublisher`] and
 /// [`DetachablePublisher`] types at the library
 /// level.
 pub mod prelude
 {
     pub use super::{
+        AsyncPublisher,
         AttachablePublisher,
+        ConsumingSubscriber,
         DetachablePublisher,
         Publisher,
         Subscriber,
+        TryPublisher,
     };
 }
 
@@ -0,0 +1,51 @@
+//! Provides a heap-based [`ConsumingSubscriber`]
+//! which redirects
+//! [`ConsumingSubscriber::consume`] calls to a
+//! delegate [`ConsumingSubscriber`].
+
+use crate::patterns::publisher_subscriber::{
+    self,
+    ConsumingSubscriber,
+    Propagation,
+};
+
+/// Stores a delegate [`ConsumingSubscriber`] in a
+/// [`Box`], allowing differently typed
+/// subscribers to share a
+/// [`super::PriorityPublisher`].
+pub struct Heap<M>
+{
+    delegate: Box<dyn ConsumingSubscriber<M>>,
+}
+
+impl<M> Heap<M>
+{
+    /// Creates a new [`Heap`] with the supplied
+    /// delegate.
+    #[must_use]
+    pub fn new(delegate: Box<dyn ConsumingSubscriber<M>>) -> Heap<M>
+    {
+        Heap { delegate }
+    }
+}
+
+impl<M> ConsumingSubscriber<M> for Heap<M>
+{
+    fn consume(
+        &self,
+        message: &M,
+    ) -> Propagation
+    {
+        self.delegate.consume(message)
+    }
+}
+
+impl<M> From<publisher_subscriber::heap::Subscriber<M>> for Heap<M>
+where
+    M: 'static,
+{
+    fn from(subscriber: publisher_subscriber::heap::Subscriber<M>) -> Self
+    {
+        Heap::new(Box::new(subscriber))
+    }
+}
@@ -0,0 +1,48 @@
+//! Provides a [`Lambda`] [`ConsumingSubscriber`]
+//! which redirects
+//! [`ConsumingSubscriber::consume`] calls to its
+//! enclosed closure.
+
+use crate::patterns::publisher_subscriber::{
+    ConsumingSubscriber,
+    Propagation,
+};
+use std::marker::PhantomData;
+
+/// Provides a type which redirects
+/// [`ConsumingSubscriber::consume`] calls to its
+/// enclosed closure.
+pub struct Lambda<C, M>
+where
+    C: Fn(&M) -> Propagation,
+{
+    delegate: C,
+    _retain_types: PhantomData<M>,
+}
+
+impl<C, M> Lambda<C, M>
+where
+    C: Fn(&M) -> Propagation,
+{
+    /// Creates a [`Lambda`] from a given closure.
+    pub fn new(delegate: C) -> Lambda<C, M>
+    {
+        Lambda {
+            delegate,
+            _retain_types: PhantomData,
+        }
+    }
+}
+
+impl<C, M> ConsumingSubscriber<M> for Lambda<C, M>
+where
+    C: Fn(&M) -> Propagation,
+{
+    fn consume(
+        &self,
+        message: &M,
+    ) -> Propagation
+    {
+        (self.delegate)(message)
+    }
+}
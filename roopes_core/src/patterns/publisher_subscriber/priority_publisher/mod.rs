@@ -0,0 +1,209 @@
+//! Contains an implementation of [`Publisher`]
+//! which delivers messages to its
+//! [`ConsumingSubscriber`]s in priority order,
+//! allowing any of them to consume the message.
+
+pub mod heap;
+pub mod lambda;
+
+use super::{
+    vec_publisher::DetachError,
+    ConsumingSubscriber,
+    Propagation,
+};
+use crate::prelude::*;
+use core::marker::PhantomData;
+pub use heap::Heap;
+pub use lambda::Lambda;
+use std::fmt::Debug;
+
+/// Implements a [`Publisher`] which delivers each
+/// message to its [`ConsumingSubscriber`]s from
+/// the highest priority to the lowest.
+/// Subscribers of equal priority receive messages
+/// in the order they were attached, and
+/// detaching never reorders the remaining
+/// subscribers.  Delivery stops as soon as a
+/// subscriber returns [`Propagation::Stop`].
+///
+/// # Examples
+/// ``` rust
+/// use publisher_subscriber::{
+///     priority_publisher::{
+///         Heap,
+///         Lambda,
+///     },
+///     PriorityPublisher,
+///     Propagation,
+/// };
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let mut publisher = PriorityPublisher::default();
+///
+/// let (low, high) = (log.clone(), log.clone());
+/// publisher.attach(
+///     0,
+///     Heap::new(Box::new(Lambda::new(move |m: &i32| {
+///         low.borrow_mut().push(("low", *m));
+///         Propagation::Continue
+///     }))),
+/// );
+/// publisher.attach(
+///     10,
+///     Heap::new(Box::new(Lambda::new(move |m: &i32| {
+///         high.borrow_mut().push(("high", *m));
+///         if *m < 0 {
+///             Propagation::Stop
+///         } else {
+///             Propagation::Continue
+///         }
+///     }))),
+/// );
+///
+/// publisher.publish(&1);
+/// assert_eq!(Propagation::Stop, publisher.dispatch(&-1));
+/// assert_eq!(vec![("high", 1), ("low", 1), ("high", -1)], *log.borrow());
+/// ```
+pub struct PriorityPublisher<M, S>
+where
+    S: ConsumingSubscriber<M>,
+{
+    listeners: Vec<Entry<S>>,
+    _retain_types: PhantomData<M>,
+}
+
+#[derive(Debug)]
+struct Entry<S>
+{
+    priority: i32,
+    subscriber: S,
+}
+
+impl<M, S> Debug for PriorityPublisher<M, S>
+where
+    S: ConsumingSubscriber<M> + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("PriorityPublisher")
+            .field("listeners", &self.listeners)
+            .finish()
+    }
+}
+
+impl<M, S> Default for PriorityPublisher<M, S>
+where
+    S: ConsumingSubscriber<M>,
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<M, S> PriorityPublisher<M, S>
+where
+    S: ConsumingSubscriber<M>,
+{
+    /// Creates a new [`PriorityPublisher`] with no
+    /// subscribers.
+    #[must_use]
+    pub fn new() -> PriorityPublisher<M, S>
+    {
+        PriorityPublisher {
+            listeners: Vec::new(),
+            _retain_types: PhantomData,
+        }
+    }
+
+    /// Attaches the given subscriber with the
+    /// given priority.  It receives messages
+    /// after every subscriber of a higher
+    /// priority, and after every subscriber of
+    /// the same priority which was attached
+    /// before it.
+    pub fn attach(
+        &mut self,
+        priority: i32,
+        attach_subscriber: S,
+    )
+    {
+        let i = self
+            .listeners
+            .partition_point(|entry| entry.priority >= priority);
+
+        self.listeners.insert(
+            i,
+            Entry {
+                priority,
+                subscriber: attach_subscriber,
+            },
+        );
+    }
+
+    /// Delivers the message in priority order,
+    /// giving back [`Propagation::Stop`] if a
+    /// subscriber consumed it.
+    pub fn dispatch(
+        &self,
+        message: &M,
+    ) -> Propagation
+    {
+        for entry in &self.listeners {
+            if entry.subscriber.consume(message) == Propagation::Stop {
+                return Propagation::Stop;
+            }
+        }
+
+        Propagation::Continue
+    }
+}
+
+impl<M, S> PriorityPublisher<M, S>
+where
+    S: ConsumingSubscriber<M> + Eq,
+{
+    /// Detaches the given subscriber so it would
+    /// no longer receive messages.  The order of
+    /// the remaining subscribers is unchanged.
+    ///
+    /// # Errors
+    /// [`DetachError::SubscriberNotFound`]: The
+    /// subscriber was not attached.
+    pub fn detach(
+        &mut self,
+        detach_subscriber: &S,
+    ) -> Result<(), DetachError>
+    {
+        let i = self
+            .listeners
+            .iter()
+            .position(|entry| entry.subscriber.eq(detach_subscriber))
+            .ok_or(DetachError::SubscriberNotFound)?;
+
+        self.listeners.remove(i);
+
+        Ok(())
+    }
+}
+
+impl<M, S> Publisher<M> for PriorityPublisher<M, S>
+where
+    S: ConsumingSubscriber<M>,
+{
+    fn publish(
+        &self,
+        message: &M,
+    )
+    {
+        self.dispatch(message);
+    }
+}
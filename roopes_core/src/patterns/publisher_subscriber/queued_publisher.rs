@@ -0,0 +1,326 @@
+//! Contains a [`QueuedPublisher`], which holds
+//! messages until they are explicitly delivered.
+
+use super::{
+    heap,
+    VecPublisher,
+};
+use crate::prelude::*;
+use std::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    collections::VecDeque,
+    fmt::Debug,
+};
+
+/// Decides what a bounded [`QueuedPublisher`]
+/// does with a message enqueued while it is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy
+{
+    /// Discard the oldest queued message to make
+    /// room for the new one.
+    DropOldest,
+
+    /// Discard the new message, leaving the queue
+    /// unchanged.
+    DropNewest,
+
+    /// Give the new message back to the caller as
+    /// an [`EnqueueError::Full`].
+    #[default]
+    Reject,
+}
+
+/// An Error which occurs while enqueuing.
+#[derive(Debug, PartialEq, Eq)]
+pub enum EnqueueError<M>
+{
+    /// The queue was full and its policy is
+    /// [`OverflowPolicy::Reject`].  Holds the
+    /// rejected message.
+    Full(M),
+}
+
+/// Wraps a delegate [`Publisher`], queueing owned
+/// messages instead of delivering them
+/// immediately.  Messages are delivered, oldest
+/// first, only when [`QueuedPublisher::pump`] or
+/// [`QueuedPublisher::drain`] is called.
+///
+/// Delivery runs to completion: every subscriber
+/// receives a message before the next one is
+/// delivered.  Messages enqueued by subscribers
+/// during delivery join the back of the queue
+/// rather than being delivered recursively, and
+/// calls to [`QueuedPublisher::pump`] or
+/// [`QueuedPublisher::drain`] from within
+/// delivery do nothing.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// let received = Rc::new(RefCell::new(Vec::new()));
+/// let mut queue = publisher_subscriber::QueuedPublisher::<u32>::default();
+///
+/// let received_ext = received.clone();
+/// queue.publisher_mut().attach(
+///     SubscribingHandler::new(handler::Lambda::new(move |m: &u32| {
+///         received_ext.borrow_mut().push(*m);
+///     }))
+///     .into_heap(),
+/// );
+///
+/// queue.enqueue(1).unwrap();
+/// queue.publish(&2);
+/// assert!(received.borrow().is_empty());
+///
+/// assert_eq!(2, queue.drain());
+/// assert_eq!(vec![1, 2], *received.borrow());
+/// ```
+pub struct QueuedPublisher<M, P = VecPublisher<M, heap::Subscriber<M>>>
+where
+    P: Publisher<M>,
+{
+    publisher: P,
+    queue: RefCell<VecDeque<M>>,
+    capacity: Option<usize>,
+    policy: OverflowPolicy,
+    dropped: Cell<usize>,
+    delivering: Cell<bool>,
+}
+
+impl<M, P> QueuedPublisher<M, P>
+where
+    P: Publisher<M>,
+{
+    /// Creates a new, unbounded [`QueuedPublisher`]
+    /// which delivers to the given [`Publisher`].
+    #[must_use]
+    pub fn new(publisher: P) -> QueuedPublisher<M, P>
+    {
+        QueuedPublisher {
+            publisher,
+            queue: RefCell::new(VecDeque::new()),
+            capacity: None,
+            policy: OverflowPolicy::default(),
+            dropped: Cell::new(0),
+            delivering: Cell::new(false),
+        }
+    }
+
+    /// Creates a new [`QueuedPublisher`] which
+    /// holds at most `capacity` messages, applying
+    /// the given [`OverflowPolicy`] once full.
+    #[must_use]
+    pub fn bounded(
+        publisher: P,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> QueuedPublisher<M, P>
+    {
+        QueuedPublisher {
+            capacity: Some(capacity),
+            policy,
+            ..QueuedPublisher::new(publisher)
+        }
+    }
+
+    /// Gives the delegate [`Publisher`].
+    pub fn publisher(&self) -> &P
+    {
+        &self.publisher
+    }
+
+    /// Gives the delegate [`Publisher`] mutably,
+    /// e.g.: to attach subscribers.
+    pub fn publisher_mut(&mut self) -> &mut P
+    {
+        &mut self.publisher
+    }
+
+    /// Adds a message to the back of the queue.
+    ///
+    /// # Errors
+    /// [`EnqueueError::Full`]: The queue was full
+    /// and its policy is
+    /// [`OverflowPolicy::Reject`].
+    pub fn enqueue(
+        &self,
+        message: M,
+    ) -> Result<(), EnqueueError<M>>
+    {
+        let mut queue = self.queue.borrow_mut();
+
+        if self
+            .capacity
+            .is_some_and(|capacity| queue.len() >= capacity)
+        {
+            if self.policy == OverflowPolicy::Reject {
+                return Err(EnqueueError::Full(message));
+            }
+
+            self.dropped.set(self.dropped.get() + 1);
+            if self.policy == OverflowPolicy::DropNewest
+                || queue.pop_front().is_none()
+            {
+                return Ok(());
+            }
+        }
+
+        queue.push_back(message);
+
+        Ok(())
+    }
+
+    /// Delivers the oldest queued message, giving
+    /// back whether there was one to deliver.
+    pub fn pump(&self) -> bool
+    {
+        if self.delivering.replace(true) {
+            return false;
+        }
+        let _guard = DeliveryGuard(&self.delivering);
+
+        self.deliver_next()
+    }
+
+    /// Delivers queued messages until the queue
+    /// is empty, including any enqueued during
+    /// delivery, giving back how many were
+    /// delivered.
+    pub fn drain(&self) -> usize
+    {
+        if self.delivering.replace(true) {
+            return 0;
+        }
+        let _guard = DeliveryGuard(&self.delivering);
+
+        let mut delivered = 0;
+        while self.deliver_next() {
+            delivered += 1;
+        }
+
+        delivered
+    }
+
+    /// Gives the number of queued messages.
+    pub fn len(&self) -> usize
+    {
+        self.queue.borrow().len()
+    }
+
+    /// Indicates whether no messages are queued.
+    pub fn is_empty(&self) -> bool
+    {
+        self.queue.borrow().is_empty()
+    }
+
+    /// Gives the maximum number of queued
+    /// messages, if bounded.
+    pub fn capacity(&self) -> Option<usize>
+    {
+        self.capacity
+    }
+
+    /// Gives the number of messages discarded by
+    /// [`OverflowPolicy::DropOldest`] or
+    /// [`OverflowPolicy::DropNewest`], or
+    /// rejected by [`OverflowPolicy::Reject`]
+    /// when sent through [`Publisher::publish`].
+    pub fn dropped(&self) -> usize
+    {
+        self.dropped.get()
+    }
+
+    fn deliver_next(&self) -> bool
+    {
+        let message = self.queue.borrow_mut().pop_front();
+
+        match message {
+            | Some(message) => {
+                self.publisher.publish(&message);
+                true
+            }
+            | None => false,
+        }
+    }
+}
+
+impl<M, P> Default for QueuedPublisher<M, P>
+where
+    P: Publisher<M> + Default,
+{
+    fn default() -> Self
+    {
+        Self::new(P::default())
+    }
+}
+
+impl<M, P> Debug for QueuedPublisher<M, P>
+where
+    M: Debug,
+    P: Publisher<M> + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("QueuedPublisher")
+            .field("publisher", &self.publisher)
+            .field("queue", &self.queue)
+            .field("capacity", &self.capacity)
+            .field("policy", &self.policy)
+            .field("dropped", &self.dropped)
+            .field("delivering", &self.delivering)
+            .finish()
+    }
+}
+
+/// Enqueues a clone of each published message.
+///
+/// **Messages which don't fit are lost.**
+/// [`Publisher::publish`] can't hand a rejected
+/// message back, so on a full queue whose policy
+/// is [`OverflowPolicy::Reject`] (the default) the
+/// message is discarded, as under
+/// [`OverflowPolicy::DropNewest`].  Such losses
+/// are only counted by
+/// [`QueuedPublisher::dropped`].  Use
+/// [`QueuedPublisher::enqueue`] to be given the
+/// rejected message instead.
+impl<M, P> Publisher<M> for QueuedPublisher<M, P>
+where
+    M: Clone,
+    P: Publisher<M>,
+{
+    fn publish(
+        &self,
+        message: &M,
+    )
+    {
+        if self.enqueue(message.clone()).is_err() {
+            self.dropped.set(self.dropped.get() + 1);
+        }
+    }
+}
+
+/// Clears the delivering flag when delivery ends,
+/// even if a subscriber panicked.
+struct DeliveryGuard<'a>(&'a Cell<bool>);
+
+impl Drop for DeliveryGuard<'_>
+{
+    fn drop(&mut self)
+    {
+        self.0.set(false);
+    }
+}
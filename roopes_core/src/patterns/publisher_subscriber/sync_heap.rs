@@ -0,0 +1,76 @@
+//! Provides a thread-safe, heap-based
+//! [`Subscriber`] which redirects
+//! [`publisher_subscriber::Subscriber::receive`]
+//! calls to a delegate [`Subscriber`].
+
+use crate::patterns::publisher_subscriber;
+use delegate::delegate;
+use std::{
+    fmt::Debug,
+    sync::Arc,
+};
+
+/// Holds a reference to a delegate [`Subscriber`]
+/// in an [`Arc`]ed delegate for later calls to
+/// [`publisher_subscriber::Subscriber::receive`].
+/// Unlike [`super::heap::Subscriber`], this type
+/// is [`Send`] and [`Sync`].
+pub struct Subscriber<M>
+{
+    delegate: Arc<dyn publisher_subscriber::Subscriber<M> + Send + Sync>,
+}
+
+impl<M> Subscriber<M>
+{
+    /// Creates a new, thread-safe, heap-based,
+    /// [`Subscriber`].
+    #[must_use]
+    pub fn new(
+        delegate: Arc<dyn publisher_subscriber::Subscriber<M> + Send + Sync>
+    ) -> Subscriber<M>
+    {
+        Self { delegate }
+    }
+}
+
+impl<M> Clone for Subscriber<M>
+{
+    fn clone(&self) -> Self
+    {
+        Subscriber::new(self.delegate.clone())
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<M> publisher_subscriber::Subscriber<M> for Subscriber<M>
+{
+    delegate! {
+        to self.delegate {
+           fn receive(&self, message: &M);
+        }
+    }
+}
+
+/// Never consumes the messages it receives.
+impl<M> publisher_subscriber::ConsumingSubscriber<M> for Subscriber<M>
+{
+    fn consume(
+        &self,
+        message: &M,
+    ) -> publisher_subscriber::Propagation
+    {
+        publisher_subscriber::Subscriber::receive(self, message);
+        publisher_subscriber::Propagation::Continue
+    }
+}
+
+impl<M> Debug for Subscriber<M>
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("Subscriber").finish()
+    }
+}
@@ -0,0 +1,182 @@
+//! Contains an implementation of [`Publisher`]
+//! which stores its [`Subscriber`]s in a [`Vec`]
+//! that may be shared between threads.
+
+use super::{
+    vec_publisher::DetachError,
+    AttachablePublisher,
+    DetachablePublisher,
+};
+use crate::prelude::*;
+use core::marker::PhantomData;
+use std::{
+    fmt::Debug,
+    sync::{
+        Arc,
+        RwLock,
+    },
+};
+
+/// Implements a [`Publisher`] based on a [`Vec`]
+/// of [`Subscriber`]s behind an [`Arc`]ed
+/// [`RwLock`].  Clones of a [`SyncVecPublisher`]
+/// share the same subscribers, so messages can be
+/// published from any thread holding a clone.
+/// Subscribers are copied out of the lock before
+/// they receive a message, so a subscriber may
+/// attach or detach through a clone from within
+/// [`Publisher::publish`].  Such changes take
+/// effect from the next message.
+///
+/// # Example
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::sync::{
+///     atomic::{
+///         AtomicBool,
+///         Ordering,
+///     },
+///     Arc,
+/// };
+///
+/// let has_run = Arc::new(AtomicBool::new(false));
+/// let mut publisher = publisher_subscriber::SyncVecPublisher::default();
+/// let has_run_ext = has_run.clone();
+/// let handler = handler::Lambda::new(move |v| {
+///     has_run_ext.store(*v, Ordering::SeqCst);
+/// });
+/// let sub_handler: SubscribingHandler<_, _> = handler.into();
+/// publisher.attach(sub_handler);
+///
+/// let worker_publisher = publisher.clone();
+/// std::thread::spawn(move || worker_publisher.publish(&true))
+///     .join()
+///     .unwrap();
+/// assert!(has_run.load(Ordering::SeqCst));
+/// ```
+pub struct SyncVecPublisher<M, S>
+where
+    S: Subscriber<M> + Send + Sync,
+{
+    listeners: Arc<RwLock<Vec<Arc<S>>>>,
+    _retain_types: PhantomData<fn(&M)>,
+}
+
+impl<M, S> Debug for SyncVecPublisher<M, S>
+where
+    S: Subscriber<M> + Send + Sync + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("SyncVecPublisher")
+            .field("listeners", &self.listeners)
+            .finish()
+    }
+}
+
+impl<M, S> Default for SyncVecPublisher<M, S>
+where
+    S: Subscriber<M> + Send + Sync,
+{
+    fn default() -> Self
+    {
+        Self::new(Vec::default())
+    }
+}
+
+impl<M, S> Clone for SyncVecPublisher<M, S>
+where
+    S: Subscriber<M> + Send + Sync,
+{
+    fn clone(&self) -> Self
+    {
+        SyncVecPublisher {
+            listeners: self.listeners.clone(),
+            _retain_types: PhantomData,
+        }
+    }
+}
+
+impl<M, S> SyncVecPublisher<M, S>
+where
+    S: Subscriber<M> + Send + Sync,
+{
+    /// Creates a new [`SyncVecPublisher`] with
+    /// the given [`Vec`] of starting
+    /// [`Subscriber`]s.
+    #[must_use]
+    pub fn new(listeners: Vec<S>) -> SyncVecPublisher<M, S>
+    {
+        let listeners = listeners.into_iter().map(Arc::new).collect();
+
+        SyncVecPublisher {
+            listeners: Arc::new(RwLock::new(listeners)),
+            _retain_types: PhantomData,
+        }
+    }
+}
+
+impl<M, S> Publisher<M> for SyncVecPublisher<M, S>
+where
+    S: Subscriber<M> + Send + Sync,
+{
+    fn publish(
+        &self,
+        message: &M,
+    )
+    {
+        let listeners = self
+            .listeners
+            .read()
+            .expect("SyncVecPublisher lock was poisoned.")
+            .clone();
+
+        for listener in listeners {
+            listener.receive(message);
+        }
+    }
+}
+
+impl<M, S> DetachablePublisher<M, S, DetachError> for SyncVecPublisher<M, S>
+where
+    S: Subscriber<M> + Send + Sync + Eq,
+{
+    fn detach(
+        &mut self,
+        detach_subscriber: &S,
+    ) -> Result<(), DetachError>
+    {
+        let mut listeners = self
+            .listeners
+            .write()
+            .expect("SyncVecPublisher lock was poisoned.");
+
+        let i = listeners
+            .iter()
+            .position(|o| o.as_ref().eq(detach_subscriber))
+            .ok_or(DetachError::SubscriberNotFound)?;
+
+        listeners.remove(i);
+
+        Ok(())
+    }
+}
+
+impl<M, S> AttachablePublisher<M, S> for SyncVecPublisher<M, S>
+where
+    S: Subscriber<M> + Send + Sync,
+{
+    fn attach(
+        &mut self,
+        attach_subscriber: S,
+    )
+    {
+        self.listeners
+            .write()
+            .expect("SyncVecPublisher lock was poisoned.")
+            .push(Arc::new(attach_subscriber));
+    }
+}
@@ -39,6 +39,7 @@ fn vec_publisher_publish()
 }
 
 #[test]
+#[allow(clippy::similar_names)]
 fn vec_publisher_detach()
 {
     let run_ct_a = Rc::new(RefCell::new(0));
@@ -117,7 +118,7 @@ fn format()
     let vp = VecPublisher::new(vec![DummySub {}]);
 
     assert_eq!(
-        format!("{:?}", vp),
+        format!("{vp:?}"),
         "VecPublisher { listeners: [DummySub] }".to_string()
     );
 }
@@ -158,5 +159,838 @@ fn heap_subscriber_fmt()
     }
     let sub = heap::Subscriber::new(Box::new(DummySub {}));
 
-    assert_eq!(format!("{:?}", sub), "Subscriber");
+    assert_eq!(format!("{sub:?}"), "Subscriber");
+}
+
+#[test]
+fn sync_vec_publisher_publish_across_threads()
+{
+    use std::sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    };
+
+    let total = Arc::new(AtomicUsize::new(0));
+    let total_ext = total.clone();
+
+    let mut publisher = publisher_subscriber::SyncVecPublisher::default();
+
+    let sub_handler: SubscribingHandler<_, _> =
+        handler::Lambda::new(move |v: &usize| {
+            total_ext.fetch_add(*v, Ordering::SeqCst);
+        })
+        .into();
+
+    publisher.attach(publisher_subscriber::sync_heap::Subscriber::new(
+        Arc::new(sub_handler),
+    ));
+
+    let workers: Vec<_> = (1..=4)
+        .map(|i| {
+            let publisher = publisher.clone();
+            std::thread::spawn(move || publisher.publish(&i))
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(10, total.load(Ordering::SeqCst));
+}
+
+#[test]
+fn sync_vec_publisher_detach_preserves_order()
+{
+    use std::sync::{
+        Arc,
+        Mutex,
+    };
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let handler = |id| {
+        let received = received.clone();
+
+        handler::Hashable::new(
+            handler::Lambda::new(move |(): &()| {
+                received.lock().unwrap().push(id);
+            }),
+            id,
+        )
+    };
+
+    let mut publisher = publisher_subscriber::SyncVecPublisher::new(vec![]);
+
+    publisher.attach(SubscribingHandler::new(handler("A")));
+    publisher.attach(SubscribingHandler::new(handler("B")));
+    publisher.attach(SubscribingHandler::new(handler("C")));
+
+    publisher
+        .detach(&SubscribingHandler::new(handler("A")))
+        .unwrap();
+
+    publisher.publish(&());
+
+    assert_eq!(vec!["B", "C"], *received.lock().unwrap());
+}
+
+#[test]
+fn async_vec_publisher_publish_in_order()
+{
+    use crate::primitives::asynchronous::{
+        executor::{
+            block_on,
+            YieldOnce,
+        },
+        BoxFuture,
+    };
+
+    struct YieldingSubscriber
+    {
+        id: &'static str,
+        received: Rc<RefCell<Vec<(&'static str, usize)>>>,
+    }
+    impl AsyncHandler<usize> for YieldingSubscriber
+    {
+        fn handle_async<'a>(
+            &'a self,
+            message: &'a usize,
+        ) -> BoxFuture<'a, ()>
+        {
+            Box::pin(async move {
+                YieldOnce::default().await;
+                self.received.borrow_mut().push((self.id, *message));
+            })
+        }
+    }
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    let mut publisher: publisher_subscriber::AsyncVecPublisher<
+        _,
+        handler::AsyncHeap<_>,
+    > = publisher_subscriber::AsyncVecPublisher::default();
+
+    publisher.attach(handler::AsyncHeap::new(Box::new(YieldingSubscriber {
+        id: "A",
+        received: received.clone(),
+    })));
+    publisher.attach(handler::AsyncHeap::new(Box::new(
+        handler::AsyncAdapter::new(handler::Lambda::new(enclose::enclose!(
+            (received) move |v: &usize| {
+                received.borrow_mut().push(("B", *v));
+            }
+        ))),
+    )));
+
+    block_on(publisher.publish_async(&1));
+
+    assert_eq!(vec![("A", 1), ("B", 1)], *received.borrow());
+}
+
+#[test]
+fn async_adapter_publish()
+{
+    use crate::primitives::asynchronous::executor::block_on;
+
+    let has_run = Rc::new(RefCell::new(false));
+    let has_run_ext = has_run.clone();
+
+    let mut publisher = publisher_subscriber::VecPublisher::default();
+    publisher.attach(SubscribingHandler::new(handler::Lambda::new(
+        move |v: &bool| {
+            (*has_run_ext.borrow_mut()) = *v;
+        },
+    )));
+
+    let publisher: publisher_subscriber::AsyncAdapter<_, _> = publisher.into();
+
+    block_on(publisher.publish_async(&true));
+
+    assert!((*has_run.borrow()));
+}
+
+fn failing_above(
+    limit: i32,
+    received: Rc<RefCell<Vec<i32>>>,
+) -> handler::TryHeap<i32, i32>
+{
+    handler::TryHeap::new(Box::new(handler::TryLambda::new(move |i: &i32| {
+        received.borrow_mut().push(limit);
+        if *i > limit {
+            Err(limit)
+        } else {
+            Ok(())
+        }
+    })))
+}
+
+#[test]
+fn try_vec_publisher_stops_on_first()
+{
+    use publisher_subscriber::try_vec_publisher::FailurePolicy;
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let mut publisher = publisher_subscriber::TryVecPublisher::new(
+        Vec::new(),
+        FailurePolicy::StopOnFirst,
+    );
+    publisher.attach(failing_above(10, received.clone()));
+    publisher.attach(failing_above(1, received.clone()));
+    publisher.attach(failing_above(0, received.clone()));
+
+    assert_eq!(Err(vec![1]), publisher.try_publish(&5));
+    assert_eq!(vec![10, 1], *received.borrow());
+}
+
+#[test]
+fn try_vec_publisher_collects()
+{
+    use publisher_subscriber::try_vec_publisher::FailurePolicy;
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let mut publisher = publisher_subscriber::TryVecPublisher::new(
+        Vec::new(),
+        FailurePolicy::Collect,
+    );
+    publisher.attach(failing_above(10, received.clone()));
+    publisher.attach(failing_above(1, received.clone()));
+    publisher.attach(failing_above(0, received.clone()));
+
+    assert_eq!(Ok(()), publisher.try_publish(&0));
+    assert_eq!(Err(vec![1, 0]), publisher.try_publish(&5));
+    assert_eq!(vec![10, 1, 0, 10, 1, 0], *received.borrow());
+}
+
+#[test]
+fn vec_publisher_detach_preserves_order()
+{
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    let handler = |id| {
+        let received = received.clone();
+
+        SubscribingHandler::new(handler::Hashable::new(
+            handler::Lambda::new(move |(): &()| {
+                received.borrow_mut().push(id);
+            }),
+            id,
+        ))
+    };
+
+    let mut publisher = publisher_subscriber::VecPublisher::default();
+
+    publisher.attach(handler("A"));
+    publisher.attach(handler("B"));
+    publisher.attach(handler("C"));
+
+    publisher.detach(&handler("A")).unwrap();
+
+    publisher.publish(&());
+
+    assert_eq!(vec!["B", "C"], *received.borrow());
+}
+
+#[test]
+fn priority_publisher_orders_and_consumes()
+{
+    use publisher_subscriber::{
+        priority_publisher::Lambda,
+        PriorityPublisher,
+        Propagation,
+    };
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut publisher = PriorityPublisher::default();
+
+    for (priority, name) in [(0, "a"), (5, "b"), (0, "c"), (5, "d")] {
+        let log = log.clone();
+        publisher.attach(
+            priority,
+            Lambda::new(move |consume: &bool| {
+                log.borrow_mut().push(name);
+                if *consume && name == "d" {
+                    Propagation::Stop
+                } else {
+                    Propagation::Continue
+                }
+            }),
+        );
+    }
+
+    assert_eq!(Propagation::Continue, publisher.dispatch(&false));
+    assert_eq!(vec!["b", "d", "a", "c"], *log.borrow());
+
+    log.borrow_mut().clear();
+    publisher.publish(&true);
+    assert_eq!(vec!["b", "d"], *log.borrow());
+}
+
+#[test]
+fn priority_publisher_accepts_every_subscriber()
+{
+    use publisher_subscriber::{
+        priority_publisher::Heap,
+        PriorityPublisher,
+        Propagation,
+    };
+    use std::sync::{
+        Arc,
+        Mutex,
+    };
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let recorder = |name: &'static str| {
+        let log = log.clone();
+        SubscribingHandler::new(handler::Lambda::new(move |(): &()| {
+            log.lock().unwrap().push(name);
+        }))
+    };
+
+    let mut publisher = PriorityPublisher::default();
+    publisher.attach(2, Heap::new(Box::new(recorder("handler"))));
+    publisher.attach(1, Heap::from(recorder("heap").into_heap()));
+    publisher.attach(
+        0,
+        Heap::new(Box::new(publisher_subscriber::sync_heap::Subscriber::new(
+            Arc::new(recorder("sync_heap")),
+        ))),
+    );
+
+    assert_eq!(Propagation::Continue, publisher.dispatch(&()));
+    assert_eq!(vec!["handler", "heap", "sync_heap"], *log.lock().unwrap());
+}
+
+#[derive(PartialEq, Eq)]
+struct Recording
+{
+    id: &'static str,
+    received: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl ConsumingSubscriber<()> for Recording
+{
+    fn consume(
+        &self,
+        (): &(),
+    ) -> publisher_subscriber::Propagation
+    {
+        self.received.borrow_mut().push(self.id);
+        publisher_subscriber::Propagation::Continue
+    }
+}
+
+#[test]
+fn priority_publisher_detach_keeps_order()
+{
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let recording = |id| {
+        Recording {
+            id,
+            received: received.clone(),
+        }
+    };
+
+    let mut publisher = publisher_subscriber::PriorityPublisher::new();
+
+    publisher.attach(1, recording("A"));
+    publisher.attach(1, recording("B"));
+    publisher.attach(1, recording("C"));
+    publisher.attach(1, recording("D"));
+
+    publisher.detach(&recording("B")).unwrap();
+    assert!(publisher.detach(&recording("B")).is_err());
+
+    publisher.publish(&());
+
+    assert_eq!(vec!["A", "C", "D"], *received.borrow());
+}
+
+#[test]
+fn vec_publisher_subscription_detaches_on_drop()
+{
+    let total = Rc::new(RefCell::new(0));
+    let publisher = publisher_subscriber::VecPublisher::default();
+
+    let subscriber = || {
+        let total = total.clone();
+        SubscribingHandler::new(handler::Heap::new(Box::new(
+            handler::Lambda::new(move |message: &i32| {
+                *total.borrow_mut() += *message;
+            }),
+        )))
+    };
+
+    let first = publisher.subscribe(subscriber());
+    let second = publisher.subscribe(subscriber());
+    publisher.publish(&1);
+    assert_eq!(2, *total.borrow());
+
+    drop(second);
+    publisher.publish(&1);
+    assert_eq!(3, *total.borrow());
+
+    first.detach();
+    publisher.publish(&1);
+    assert_eq!(3, *total.borrow());
+}
+
+#[test]
+fn vec_publisher_subscribe_during_publish()
+{
+    type Heap = SubscribingHandler<handler::Heap<i32>, i32>;
+
+    fn subscriber<F>(f: F) -> Heap
+    where
+        F: Fn(&i32) + 'static,
+    {
+        SubscribingHandler::new(handler::Heap::new(Box::new(
+            handler::Lambda::new(f),
+        )))
+    }
+
+    let publisher = Rc::new(VecPublisher::<i32, Heap>::default());
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let subscriptions = Rc::new(RefCell::new(Vec::new()));
+
+    let weak_publisher = Rc::downgrade(&publisher);
+    let (received_ext, subscriptions_ext) =
+        (received.clone(), subscriptions.clone());
+    let first = publisher.subscribe(subscriber(move |message| {
+        received_ext.borrow_mut().push(*message);
+        let received_ext = received_ext.clone();
+        let late = weak_publisher.upgrade().unwrap().subscribe(subscriber(
+            move |message| {
+                received_ext.borrow_mut().push(message * 100);
+            },
+        ));
+        subscriptions_ext.borrow_mut().push(late);
+    }));
+
+    publisher.publish(&1);
+    assert_eq!(vec![1], *received.borrow());
+
+    drop(first);
+    publisher.publish(&2);
+    assert_eq!(vec![1, 200], *received.borrow());
+
+    subscriptions.borrow_mut().clear();
+}
+
+#[test]
+fn weak_publisher_prunes_dropped_subscribers()
+{
+    let total = Rc::new(RefCell::new(0));
+    let publisher = publisher_subscriber::WeakPublisher::new();
+
+    let subscriber = || {
+        let total = total.clone();
+        Rc::new(SubscribingHandler::new(handler::Lambda::new(
+            move |message: &i32| *total.borrow_mut() += message,
+        )))
+    };
+
+    let first = subscriber();
+    let second = subscriber();
+    publisher.attach(&first);
+    publisher.attach(&second);
+
+    publisher.publish(&1);
+    assert_eq!(2, *total.borrow());
+
+    drop(second);
+    assert_eq!(1, publisher.publish_and_prune(&1));
+    assert_eq!(3, *total.borrow());
+    assert_eq!(1, publisher.len());
+
+    publisher.detach(&first).unwrap();
+    assert!(publisher.is_empty());
+    assert_eq!(0, publisher.prune());
+}
+
+#[test]
+fn topic_matching()
+{
+    use publisher_subscriber::topic_publisher::Topic;
+
+    assert!("sensor.*.temp".is_pattern());
+    assert!(!"sensor.kitchen.temp".is_pattern());
+    assert!("sensor.*.temp".is_match(&"sensor.kitchen.temp"));
+    assert!(!"sensor.*.temp".is_match(&"sensor.kitchen.humidity"));
+    assert!(!"sensor.*.temp".is_match(&"sensor.temp"));
+    assert!("sensor.#".is_match(&"sensor"));
+    assert!("sensor.#".is_match(&"sensor.kitchen.temp"));
+    assert!(!"sensor.#".is_match(&"actuator.kitchen"));
+    assert!("sensor.#".to_string().is_match(&"sensor.a".to_string()));
+}
+
+#[test]
+fn topic_publisher_routes_by_topic()
+{
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let mut publisher = publisher_subscriber::TopicPublisher::default();
+
+    let subscriber = |name: &'static str| {
+        let received = received.clone();
+        SubscribingHandler::new(handler::Hashable::new(
+            handler::Lambda::new(move |message: &i32| {
+                received.borrow_mut().push((name, *message));
+            }),
+            name,
+        ))
+    };
+
+    publisher.attach("sensor.*.temp", subscriber("temps"));
+    publisher.attach("sensor.#", subscriber("all"));
+    publisher.attach("sensor.kitchen.temp", subscriber("kitchen"));
+
+    publisher.publish_to(&"sensor.kitchen.temp", &1);
+    publisher.publish(&("sensor.garage.door", 2));
+    publisher.publish_to(&"actuator.kitchen.fan", &3);
+    assert_eq!(
+        vec![("kitchen", 1), ("temps", 1), ("all", 1), ("all", 2)],
+        *received.borrow()
+    );
+
+    received.borrow_mut().clear();
+    publisher.detach(&"sensor.#", &subscriber("all")).unwrap();
+    publisher
+        .detach(&"sensor.kitchen.temp", &subscriber("kitchen"))
+        .unwrap();
+    assert!(publisher
+        .detach(&"sensor.kitchen.temp", &subscriber("kitchen"))
+        .is_err());
+
+    publisher.publish_to(&"sensor.kitchen.temp", &4);
+    assert_eq!(vec![("temps", 4)], *received.borrow());
+}
+
+#[test]
+fn event_bus_dispatches_by_type()
+{
+    #[derive(Debug, PartialEq)]
+    struct Opened(&'static str);
+
+    let opened = Rc::new(RefCell::new(Vec::new()));
+    let numbers = Rc::new(RefCell::new(Vec::new()));
+    let mut bus = publisher_subscriber::EventBus::new();
+
+    let opened_ext = opened.clone();
+    bus.attach_handler(handler::Lambda::new(move |e: &Opened| {
+        opened_ext.borrow_mut().push(e.0);
+    }));
+    let numbers_ext = numbers.clone();
+    let subscription = bus.subscribe(SubscribingHandler::new(
+        handler::Lambda::new(move |n: &u8| numbers_ext.borrow_mut().push(*n)),
+    ));
+
+    assert_eq!(1, bus.subscriber_count::<Opened>());
+    assert_eq!(1, bus.subscriber_count::<u8>());
+    assert_eq!(0, bus.subscriber_count::<u16>());
+
+    bus.publish(&Opened("file"));
+    bus.publish(&7_u8);
+    bus.publish(&7_u16);
+
+    drop(subscription);
+    bus.publish(&8_u8);
+
+    assert_eq!(vec!["file"], *opened.borrow());
+    assert_eq!(vec![7], *numbers.borrow());
+    assert_eq!(0, bus.subscriber_count::<u8>());
+}
+
+#[test]
+fn event_bus_subscribe_during_publish()
+{
+    let bus = Rc::new(publisher_subscriber::EventBus::new());
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let subscriptions = Rc::new(RefCell::new(Vec::new()));
+
+    let weak_bus = Rc::downgrade(&bus);
+    let (received_ext, subscriptions_ext) =
+        (received.clone(), subscriptions.clone());
+    let first = bus.subscribe(SubscribingHandler::new(handler::Lambda::new(
+        move |n: &u32| {
+            let received = received_ext.clone();
+            let bus = weak_bus.upgrade().unwrap();
+            subscriptions_ext.borrow_mut().push(bus.subscribe(
+                SubscribingHandler::new(handler::Lambda::new(
+                    move |s: &String| received.borrow_mut().push(s.clone()),
+                )),
+            ));
+            bus.publish(&format!("got {n}"));
+        },
+    )));
+
+    bus.publish(&1_u32);
+    assert_eq!(vec!["got 1"], *received.borrow());
+
+    drop(first);
+    subscriptions.borrow_mut().clear();
+}
+
+#[test]
+fn queued_publisher_overflow_policies()
+{
+    use publisher_subscriber::queued_publisher::{
+        EnqueueError,
+        OverflowPolicy,
+    };
+
+    fn queue(
+        policy: OverflowPolicy
+    ) -> publisher_subscriber::QueuedPublisher<u32>
+    {
+        let queue = publisher_subscriber::QueuedPublisher::bounded(
+            VecPublisher::default(),
+            2,
+            policy,
+        );
+        for message in 1..=3 {
+            let _ = queue.enqueue(message);
+        }
+
+        queue
+    }
+
+    let drop_oldest = queue(OverflowPolicy::DropOldest);
+    assert_eq!(2, drop_oldest.len());
+    assert_eq!(1, drop_oldest.dropped());
+
+    let drop_newest = queue(OverflowPolicy::DropNewest);
+    assert_eq!(2, drop_newest.len());
+    assert_eq!(1, drop_newest.dropped());
+
+    let reject = queue(OverflowPolicy::Reject);
+    assert_eq!(Err(EnqueueError::Full(4)), reject.enqueue(4));
+    assert_eq!(0, reject.dropped());
+    reject.publish(&4);
+    assert_eq!(2, reject.len());
+    assert_eq!(1, reject.dropped());
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    for (mut queue, expected) in [
+        (drop_oldest, vec![2, 3]),
+        (drop_newest, vec![1, 2]),
+        (reject, vec![1, 2]),
+    ] {
+        let received_ext = received.clone();
+        queue.publisher_mut().attach(
+            SubscribingHandler::new(handler::Lambda::new(move |m: &u32| {
+                received_ext.borrow_mut().push(*m);
+            }))
+            .into_heap(),
+        );
+
+        assert!(queue.pump());
+        assert_eq!(1, queue.drain());
+        assert!(!queue.pump());
+        assert_eq!(expected, received.take());
+    }
+}
+
+#[test]
+fn queued_publisher_runs_to_completion()
+{
+    use publisher_subscriber::QueuedPublisher;
+    use std::rc::Weak;
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let queue = Rc::new_cyclic(|weak: &Weak<QueuedPublisher<u32>>| {
+        let mut queue = QueuedPublisher::<u32>::default();
+
+        for name in ["a", "b"] {
+            let (log, weak) = (log.clone(), weak.clone());
+            queue.publisher_mut().attach(
+                SubscribingHandler::new(handler::Lambda::new(
+                    move |m: &u32| {
+                        log.borrow_mut().push(format!("{name}{m}"));
+
+                        let queue = weak.upgrade().unwrap();
+                        assert_eq!(0, queue.drain());
+                        if name == "a" && *m < 3 {
+                            queue.publish(&(m + 1));
+                        }
+                    },
+                ))
+                .into_heap(),
+            );
+        }
+
+        queue
+    });
+
+    queue.publish(&1);
+    assert!(log.borrow().is_empty());
+
+    assert_eq!(3, queue.drain());
+    assert_eq!(vec!["a1", "b1", "a2", "b2", "a3", "b3"], *log.borrow());
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn channel_publisher_shutdown_flushes_pending()
+{
+    use std::sync::mpsc;
+
+    let (received_tx, received_rx) = mpsc::channel();
+    let publisher = publisher_subscriber::ChannelPublisher::new(move || {
+        let mut publisher = VecPublisher::default();
+        publisher.attach(
+            SubscribingHandler::new(handler::Lambda::new(move |m: &u32| {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                received_tx.send(*m).unwrap();
+            }))
+            .into_heap(),
+        );
+        publisher
+    });
+
+    for message in 0..20 {
+        publisher.send(message).unwrap();
+    }
+    publisher.shutdown().unwrap();
+
+    assert_eq!(
+        (0..20).collect::<Vec<_>>(),
+        received_rx.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn channel_publisher_into_join_handle_flushes_pending()
+{
+    use std::sync::mpsc;
+
+    let (received_tx, received_rx) = mpsc::channel();
+    let publisher = publisher_subscriber::ChannelPublisher::new(move || {
+        let mut publisher = VecPublisher::default();
+        publisher.attach(
+            SubscribingHandler::new(handler::Lambda::new(move |m: &u32| {
+                received_tx.send(*m).unwrap();
+            }))
+            .into_heap(),
+        );
+        publisher
+    });
+
+    for message in 0..5 {
+        publisher.send(message).unwrap();
+    }
+    let dispatcher = publisher.into_join_handle();
+    dispatcher.join().unwrap();
+
+    assert_eq!(
+        (0..5).collect::<Vec<_>>(),
+        received_rx.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn channel_publisher_bounded_backpressure()
+{
+    use std::sync::{
+        mpsc::{
+            self,
+            TrySendError,
+        },
+        Arc,
+        Barrier,
+    };
+
+    let gate = Arc::new(Barrier::new(2));
+    let (received_tx, received_rx) = mpsc::channel();
+
+    let gate_ext = gate.clone();
+    let publisher =
+        publisher_subscriber::ChannelPublisher::bounded(1, move || {
+            let mut publisher = VecPublisher::default();
+            publisher.attach(
+                SubscribingHandler::new(handler::Lambda::new(
+                    move |m: &u32| {
+                        gate_ext.wait();
+                        received_tx.send(*m).unwrap();
+                    },
+                ))
+                .into_heap(),
+            );
+            publisher
+        });
+
+    // The dispatcher takes the first message and waits at the gate,
+    // leaving room for exactly one more.
+    publisher.send(1).unwrap();
+    while publisher.try_send(2).is_err() {}
+    assert!(matches!(publisher.try_send(3), Err(TrySendError::Full(3))));
+
+    gate.wait();
+    gate.wait();
+    drop(publisher);
+
+    assert_eq!(vec![1, 2], received_rx.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn sync_vec_publisher_attach_and_detach_during_publish()
+{
+    use std::sync::{
+        Arc,
+        Mutex,
+    };
+
+    type SyncSubscriber = SubscribingHandler<
+        handler::Hashable<handler::SyncHeap<i32>, i32, &'static str>,
+        i32,
+    >;
+
+    fn subscriber<F>(
+        id: &'static str,
+        f: F,
+    ) -> SyncSubscriber
+    where
+        F: Fn(&i32) + Send + Sync + 'static,
+    {
+        SubscribingHandler::new(handler::Hashable::new(
+            handler::SyncHeap::new(Arc::new(handler::Lambda::new(f))),
+            id,
+        ))
+    }
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let mut publisher = publisher_subscriber::SyncVecPublisher::default();
+    let publisher_ext = Mutex::new(publisher.clone());
+
+    let received_ext = received.clone();
+    publisher.attach(subscriber("first", move |message| {
+        received_ext.lock().unwrap().push(*message);
+
+        let received_ext = received_ext.clone();
+        let mut publisher = publisher_ext.lock().unwrap();
+        publisher.attach(subscriber("later", move |message| {
+            received_ext.lock().unwrap().push(message * 100);
+        }));
+        publisher.detach(&subscriber("first", |_| {})).unwrap();
+    }));
+
+    publisher.publish(&1);
+    assert_eq!(vec![1], *received.lock().unwrap());
+
+    publisher.publish(&2);
+    assert_eq!(vec![1, 200], *received.lock().unwrap());
+}
+
+#[test]
+fn vec_publisher_is_send_with_send_subscribers()
+{
+    fn assert_send<T: Send>() {}
+
+    assert_send::<
+        VecPublisher<u8, publisher_subscriber::sync_heap::Subscriber<u8>>,
+    >();
 }
@@ -0,0 +1,286 @@
+//! Contains a [`TopicPublisher`], which routes
+//! each message only to the [`Subscriber`]s
+//! attached to a matching [`Topic`].
+
+use super::{
+    heap,
+    vec_publisher::DetachError,
+};
+use crate::prelude::*;
+use core::marker::PhantomData;
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    hash::Hash,
+};
+
+/// A key which messages are published under, and
+/// which [`Subscriber`]s attach to.  A topic
+/// attached to may be a pattern, matching many
+/// published topics.
+///
+/// The [`String`] and [`str`] implementations are
+/// hierarchical: topics are split into segments
+/// on `.`, a `*` segment matches exactly one
+/// segment, and a trailing `#` segment matches
+/// any number of remaining segments, including
+/// none.  E.g.: `sensor.*.temp` matches
+/// `sensor.kitchen.temp`, and `sensor.#` matches
+/// both `sensor` and `sensor.kitchen.humidity`.
+///
+/// Other types may implement [`Topic`] without
+/// any methods to use exact matching.
+pub trait Topic: Eq + Hash
+{
+    /// Indicates whether this topic, when
+    /// attached to, should be compared against
+    /// published topics with [`Topic::is_match`]
+    /// rather than looked up exactly.
+    fn is_pattern(&self) -> bool
+    {
+        false
+    }
+
+    /// Indicates whether this pattern matches the
+    /// given published topic.
+    fn is_match(
+        &self,
+        topic: &Self,
+    ) -> bool
+    {
+        self == topic
+    }
+}
+
+impl Topic for String
+{
+    fn is_pattern(&self) -> bool
+    {
+        self.as_str().is_pattern()
+    }
+
+    fn is_match(
+        &self,
+        topic: &Self,
+    ) -> bool
+    {
+        self.as_str().is_match(&topic.as_str())
+    }
+}
+
+impl Topic for &str
+{
+    fn is_pattern(&self) -> bool
+    {
+        self.split('.')
+            .any(|segment| segment == "*" || segment == "#")
+    }
+
+    fn is_match(
+        &self,
+        topic: &Self,
+    ) -> bool
+    {
+        let mut pattern = self.split('.');
+        let mut topic = topic.split('.');
+
+        loop {
+            match (pattern.next(), topic.next()) {
+                | (Some("#"), _) => return pattern.next().is_none(),
+                | (Some("*"), Some(_)) => {}
+                | (Some(p), Some(t)) if p == t => {}
+                | (None, None) => return true,
+                | _ => return false,
+            }
+        }
+    }
+}
+
+/// Implements a [`Publisher`] of `(topic,
+/// message)` pairs which delivers each message
+/// only to the [`Subscriber`]s attached to a
+/// [`Topic`] matching the one it was published
+/// under.  Subscribers attached to the exact
+/// topic receive the message first, in attachment
+/// order, followed by those attached to matching
+/// patterns, in attachment order.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// let temperatures = Rc::new(RefCell::new(Vec::new()));
+/// let mut publisher = publisher_subscriber::TopicPublisher::default();
+///
+/// let temperatures_ext = temperatures.clone();
+/// publisher.attach(
+///     "sensor.*.temp",
+///     SubscribingHandler::new(handler::Lambda::new(move |reading: &f32| {
+///         temperatures_ext.borrow_mut().push(*reading);
+///     }))
+///     .into_heap(),
+/// );
+///
+/// publisher.publish_to(&"sensor.kitchen.temp", &21.5);
+/// publisher.publish_to(&"sensor.kitchen.humidity", &40.0);
+/// publisher.publish(&("sensor.garage.temp", 12.0));
+///
+/// assert_eq!(vec![21.5, 12.0], *temperatures.borrow());
+/// ```
+pub struct TopicPublisher<K, M, S = heap::Subscriber<M>>
+where
+    K: Topic,
+    S: Subscriber<M>,
+{
+    exact: HashMap<K, Vec<S>>,
+    patterns: Vec<(K, S)>,
+    _retain_types: PhantomData<M>,
+}
+
+impl<K, M, S> Debug for TopicPublisher<K, M, S>
+where
+    K: Topic + Debug,
+    S: Subscriber<M> + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("TopicPublisher")
+            .field("exact", &self.exact)
+            .field("patterns", &self.patterns)
+            .finish()
+    }
+}
+
+impl<K, M, S> Default for TopicPublisher<K, M, S>
+where
+    K: Topic,
+    S: Subscriber<M>,
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<K, M, S> TopicPublisher<K, M, S>
+where
+    K: Topic,
+    S: Subscriber<M>,
+{
+    /// Creates a new [`TopicPublisher`] with no
+    /// subscribers.
+    #[must_use]
+    pub fn new() -> TopicPublisher<K, M, S>
+    {
+        TopicPublisher {
+            exact: HashMap::new(),
+            patterns: Vec::new(),
+            _retain_types: PhantomData,
+        }
+    }
+
+    /// Attaches the given [`Subscriber`] to the
+    /// given [`Topic`], which may be a pattern.
+    pub fn attach(
+        &mut self,
+        topic: K,
+        attach_subscriber: S,
+    )
+    {
+        if topic.is_pattern() {
+            self.patterns.push((topic, attach_subscriber));
+        } else {
+            self.exact.entry(topic).or_default().push(attach_subscriber);
+        }
+    }
+
+    /// Delivers the message to every
+    /// [`Subscriber`] attached to a [`Topic`]
+    /// matching the given one.
+    pub fn publish_to(
+        &self,
+        topic: &K,
+        message: &M,
+    )
+    {
+        for subscriber in self.exact.get(topic).into_iter().flatten() {
+            subscriber.receive(message);
+        }
+
+        for (_, subscriber) in self
+            .patterns
+            .iter()
+            .filter(|(pattern, _)| pattern.is_match(topic))
+        {
+            subscriber.receive(message);
+        }
+    }
+}
+
+impl<K, M, S> TopicPublisher<K, M, S>
+where
+    K: Topic,
+    S: Subscriber<M> + Eq,
+{
+    /// Detaches the given [`Subscriber`] from the
+    /// given [`Topic`], so it would no longer
+    /// receive messages published under it.  It
+    /// remains attached to any other topics.
+    ///
+    /// # Errors
+    /// [`DetachError::SubscriberNotFound`]: The
+    /// subscriber was not attached to the topic.
+    pub fn detach(
+        &mut self,
+        topic: &K,
+        detach_subscriber: &S,
+    ) -> Result<(), DetachError>
+    {
+        if topic.is_pattern() {
+            let i = self
+                .patterns
+                .iter()
+                .position(|(k, s)| k == topic && s == detach_subscriber)
+                .ok_or(DetachError::SubscriberNotFound)?;
+
+            self.patterns.remove(i);
+        } else {
+            let subscribers = self
+                .exact
+                .get_mut(topic)
+                .ok_or(DetachError::SubscriberNotFound)?;
+            let i = subscribers
+                .iter()
+                .position(|s| s == detach_subscriber)
+                .ok_or(DetachError::SubscriberNotFound)?;
+
+            subscribers.remove(i);
+            if subscribers.is_empty() {
+                self.exact.remove(topic);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<K, M, S> Publisher<(K, M)> for TopicPublisher<K, M, S>
+where
+    K: Topic,
+    S: Subscriber<M>,
+{
+    fn publish(
+        &self,
+        (topic, message): &(K, M),
+    )
+    {
+        self.publish_to(topic, message);
+    }
+}
@@ -0,0 +1,204 @@
+//! Contains an implementation of
+//! [`TryPublisher`] which stores its
+//! subscribers, as [`TryHandler`]s, in a
+//! [`Vec`].
+
+use super::{
+    vec_publisher::DetachError,
+    TryPublisher,
+};
+use crate::prelude::*;
+use core::marker::PhantomData;
+use std::fmt::Debug;
+
+/// Decides what a [`TryVecPublisher`] does when
+/// one of its subscribers fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FailurePolicy
+{
+    /// Stop delivering the message at the first
+    /// subscriber which fails.  Subscribers
+    /// attached after it do not receive the
+    /// message.
+    #[default]
+    StopOnFirst,
+
+    /// Deliver the message to every subscriber,
+    /// collecting all of the errors which occur.
+    Collect,
+}
+
+/// Implements a [`TryPublisher`] based on a
+/// [`Vec`] of [`TryHandler`]s.  Subscribers are
+/// called in attachment order, and failures are
+/// treated according to the publisher's
+/// [`FailurePolicy`].
+///
+/// # Examples
+/// ``` rust
+/// use publisher_subscriber::try_vec_publisher::FailurePolicy;
+/// use roopes::prelude::*;
+///
+/// let mut publisher = publisher_subscriber::TryVecPublisher::new(
+///     Vec::new(),
+///     FailurePolicy::Collect,
+/// );
+/// publisher.attach(handler::TryHeap::new(Box::new(handler::TryLambda::new(
+///     |i: &i32| if *i > 0 { Ok(()) } else { Err("not positive") },
+/// ))));
+/// publisher.attach(handler::TryHeap::new(Box::new(handler::TryLambda::new(
+///     |i: &i32| if *i % 2 == 0 { Ok(()) } else { Err("not even") },
+/// ))));
+///
+/// assert_eq!(Ok(()), publisher.try_publish(&2));
+/// assert_eq!(Err(vec!["not even"]), publisher.try_publish(&1));
+/// assert_eq!(
+///     Err(vec!["not positive", "not even"]),
+///     publisher.try_publish(&-1)
+/// );
+/// ```
+pub struct TryVecPublisher<M, S>
+where
+    S: TryHandler<M>,
+{
+    listeners: Vec<S>,
+    policy: FailurePolicy,
+    _retain_types: PhantomData<M>,
+}
+
+impl<M, S> Debug for TryVecPublisher<M, S>
+where
+    S: TryHandler<M> + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("TryVecPublisher")
+            .field("listeners", &self.listeners)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl<M, S> Default for TryVecPublisher<M, S>
+where
+    S: TryHandler<M>,
+{
+    fn default() -> Self
+    {
+        Self::new(Vec::default(), FailurePolicy::default())
+    }
+}
+
+impl<M, S> TryVecPublisher<M, S>
+where
+    S: TryHandler<M>,
+{
+    /// Creates a new [`TryVecPublisher`] with the
+    /// given [`Vec`] of starting subscribers and
+    /// [`FailurePolicy`].
+    #[must_use]
+    pub fn new(
+        listeners: Vec<S>,
+        policy: FailurePolicy,
+    ) -> TryVecPublisher<M, S>
+    {
+        TryVecPublisher {
+            listeners,
+            policy,
+            _retain_types: PhantomData,
+        }
+    }
+
+    /// Gives the [`FailurePolicy`] currently in
+    /// use.
+    #[must_use]
+    pub fn policy(&self) -> FailurePolicy
+    {
+        self.policy
+    }
+
+    /// Changes the [`FailurePolicy`] used for
+    /// subsequent publications.
+    pub fn set_policy(
+        &mut self,
+        policy: FailurePolicy,
+    )
+    {
+        self.policy = policy;
+    }
+
+    /// Attaches the given subscriber so it would
+    /// now receive messages.
+    pub fn attach(
+        &mut self,
+        attach_subscriber: S,
+    )
+    {
+        self.listeners.push(attach_subscriber);
+    }
+}
+
+impl<M, S> TryVecPublisher<M, S>
+where
+    S: TryHandler<M> + Eq,
+{
+    /// Detaches the given subscriber so it would
+    /// no longer receive messages.
+    ///
+    /// # Errors
+    /// [`DetachError::SubscriberNotFound`]: The
+    /// subscriber was not attached.
+    pub fn detach(
+        &mut self,
+        detach_subscriber: &S,
+    ) -> Result<(), DetachError>
+    {
+        let i = self
+            .listeners
+            .iter()
+            .position(|o| o.eq(detach_subscriber))
+            .ok_or(DetachError::SubscriberNotFound)?;
+
+        self.listeners.remove(i);
+
+        Ok(())
+    }
+}
+
+impl<M, S> TryPublisher<M> for TryVecPublisher<M, S>
+where
+    S: TryHandler<M>,
+{
+    /// The errors produced by the subscribers
+    /// which failed, in attachment order.  Under
+    /// [`FailurePolicy::StopOnFirst`] this holds
+    /// exactly one error.
+    type Error = Vec<S::Error>;
+
+    fn try_publish(
+        &self,
+        message: &M,
+    ) -> Result<(), Self::Error>
+    {
+        let mut errors = Vec::new();
+
+        for listener in &self.listeners {
+            if let Err(error) = listener.try_handle(message) {
+                errors.push(error);
+
+                if self.policy == FailurePolicy::StopOnFirst {
+                    break;
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -5,15 +5,27 @@ use super::{
     AttachablePublisher,
     DetachablePublisher,
 };
-use crate::prelude::*;
-use core::marker::PhantomData;
-use std::{
-    cell::RefCell,
-    fmt::Debug,
+use crate::{
+    patterns::subscription::registry::Registry,
+    prelude::*,
 };
+use core::marker::PhantomData;
+use std::fmt::Debug;
 
 /// Implements a [`Publisher`] based on a [`Vec`]
-/// of [`Subscriber`]s. # Example
+/// of [`Subscriber`]s.  [`VecPublisher::subscribe`]
+/// attaches a [`Subscriber`] for the lifetime of
+/// a [`Subscription`].
+///
+/// [`Subscriber`]s may subscribe, or drop their
+/// [`Subscription`]s, from within
+/// [`Publisher::publish`].  [`Subscriber`]s
+/// subscribed during a publication first receive
+/// the next message; those unsubscribed during a
+/// publication receive nothing further, including
+/// the rest of the publication in progress.
+///
+/// # Example
 /// ``` rust
 /// use roopes::prelude::*;
 /// use std::{
@@ -40,7 +52,7 @@ pub struct VecPublisher<M, S>
 where
     S: Subscriber<M>,
 {
-    listeners: Vec<S>,
+    listeners: Registry<Vec<(usize, S)>>,
     _retain_types: PhantomData<M>,
 }
 
@@ -52,6 +64,7 @@ pub enum DetachError
     SubscriberNotFound,
 }
 
+#[allow(clippy::missing_fields_in_debug)]
 impl<M, S> Debug for VecPublisher<M, S>
 where
     S: Subscriber<M> + Debug,
@@ -61,8 +74,13 @@ where
         f: &mut std::fmt::Formatter<'_>,
     ) -> std::fmt::Result
     {
+        let listeners = self.listeners.storage();
+
         f.debug_struct("VecPublisher")
-            .field("listeners", &self.listeners)
+            .field(
+                "listeners",
+                &listeners.iter().map(|(_, s)| s).collect::<Vec<_>>(),
+            )
             .finish()
     }
 }
@@ -87,11 +105,26 @@ where
     #[must_use]
     pub fn new(listeners: Vec<S>) -> VecPublisher<M, S>
     {
+        let listeners: Vec<_> = listeners.into_iter().enumerate().collect();
+        let next_id = listeners.len();
+
         VecPublisher {
-            listeners,
+            listeners: Registry::new(listeners, next_id),
             _retain_types: PhantomData,
         }
     }
+
+    /// Attaches the given [`Subscriber`] until
+    /// the returned [`Subscription`] is dropped.
+    /// Unlike [`DetachablePublisher::detach`], the
+    /// subscriber needn't implement [`Eq`].
+    pub fn subscribe(
+        &self,
+        attach_subscriber: S,
+    ) -> Subscription
+    {
+        self.listeners.subscribe(attach_subscriber)
+    }
 }
 
 impl<M, S> Publisher<M> for VecPublisher<M, S>
@@ -103,7 +136,8 @@ where
         message: &M,
     )
     {
-        self.listeners.iter().for_each(|s| s.receive(message));
+        self.listeners
+            .dispatch(&mut |_, listener| listener.receive(message));
     }
 }
 
@@ -116,14 +150,13 @@ where
         detach_subscriber: &S,
     ) -> Result<(), DetachError>
     {
-        let (i, _) = self
-            .listeners
+        let mut listeners = self.listeners.storage_mut();
+        let i = listeners
             .iter()
-            .enumerate()
-            .find(|(_, o)| o.eq(&detach_subscriber))
+            .position(|(_, s)| s.eq(detach_subscriber))
             .ok_or(DetachError::SubscriberNotFound)?;
 
-        self.listeners.swap_remove(i);
+        listeners.remove(i);
 
         Ok(())
     }
@@ -138,6 +171,6 @@ where
         attach_subscriber: S,
     )
     {
-        self.listeners.push(attach_subscriber);
+        self.listeners.attach(attach_subscriber);
     }
 }
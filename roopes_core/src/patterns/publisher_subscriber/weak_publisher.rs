@@ -0,0 +1,184 @@
+//! Contains a [`Publisher`] which holds its
+//! [`Subscriber`]s weakly, forgetting them once
+//! they have been dropped.
+
+use super::vec_publisher::DetachError;
+use crate::{
+    patterns::subscription::registry::Registry,
+    prelude::*,
+};
+use std::rc::{
+    Rc,
+    Weak,
+};
+
+type WeakSubscribers<M> = Vec<(usize, Weak<dyn Subscriber<M>>)>;
+
+/// Implements a [`Publisher`] backed by a [`Vec`]
+/// of [`Weak`] references to [`Subscriber`]s.
+/// The [`WeakPublisher`] never keeps a subscriber
+/// alive: subscribers which have been dropped are
+/// skipped, and pruned, on the next publication.
+///
+/// As with [`super::VecPublisher`], subscribers
+/// may be attached or detached from within
+/// [`Publisher::publish`].
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// let total = Rc::new(RefCell::new(0));
+/// let publisher = publisher_subscriber::WeakPublisher::default();
+///
+/// let subscriber = Rc::new(SubscribingHandler::new(handler::Lambda::new({
+///     let total = total.clone();
+///     move |message: &i32| *total.borrow_mut() += message
+/// })));
+/// publisher.attach(&subscriber);
+///
+/// assert_eq!(0, publisher.publish_and_prune(&2));
+/// drop(subscriber);
+/// assert_eq!(1, publisher.publish_and_prune(&2));
+/// assert_eq!(2, *total.borrow());
+/// ```
+pub struct WeakPublisher<M>
+{
+    listeners: Registry<WeakSubscribers<M>>,
+}
+
+impl<M> Default for WeakPublisher<M>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<M> WeakPublisher<M>
+{
+    /// Creates a new [`WeakPublisher`] with no
+    /// subscribers.
+    #[must_use]
+    pub fn new() -> WeakPublisher<M>
+    {
+        WeakPublisher {
+            listeners: Registry::new(Vec::new(), 0),
+        }
+    }
+
+    /// Attaches a weak reference to the given
+    /// [`Subscriber`], which receives messages for
+    /// as long as it is alive.
+    pub fn attach<S>(
+        &self,
+        attach_subscriber: &Rc<S>,
+    ) where
+        S: Subscriber<M> + 'static,
+    {
+        let subscriber: Weak<dyn Subscriber<M>> =
+            Rc::downgrade(attach_subscriber) as Weak<_>;
+
+        self.listeners.attach(subscriber);
+    }
+
+    /// Detaches the given [`Subscriber`], compared
+    /// by identity rather than [`Eq`].
+    ///
+    /// # Errors
+    /// [`DetachError::SubscriberNotFound`]: The
+    /// subscriber was not attached.
+    pub fn detach<S>(
+        &self,
+        detach_subscriber: &Rc<S>,
+    ) -> Result<(), DetachError>
+    where
+        S: Subscriber<M> + 'static,
+    {
+        let subscriber: Weak<dyn Subscriber<M>> =
+            Rc::downgrade(detach_subscriber) as Weak<_>;
+        let id = *self
+            .listeners
+            .find(&mut |listener| Weak::ptr_eq(listener, &subscriber))
+            .first()
+            .ok_or(DetachError::SubscriberNotFound)?;
+
+        self.listeners.detach(id);
+
+        Ok(())
+    }
+
+    /// Delivers the message to every live
+    /// [`Subscriber`], giving back the number of
+    /// dropped subscribers which were pruned.
+    pub fn publish_and_prune(
+        &self,
+        message: &M,
+    ) -> usize
+    {
+        let mut dead = Vec::new();
+
+        self.listeners.dispatch(&mut |id, listener| {
+            match listener.upgrade() {
+                | Some(listener) => listener.receive(message),
+                | None => dead.push(id),
+            }
+        });
+
+        self.remove(&dead)
+    }
+
+    /// Prunes dropped [`Subscriber`]s without
+    /// publishing, giving back the number pruned.
+    pub fn prune(&self) -> usize
+    {
+        let dead = self
+            .listeners
+            .find(&mut |listener| listener.strong_count() == 0);
+
+        self.remove(&dead)
+    }
+
+    /// Gives the number of attached subscribers,
+    /// including dropped ones not yet pruned.
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        self.listeners.len()
+    }
+
+    /// Indicates whether no subscribers are
+    /// attached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    fn remove(
+        &self,
+        ids: &[usize],
+    ) -> usize
+    {
+        for id in ids {
+            self.listeners.detach(*id);
+        }
+
+        ids.len()
+    }
+}
+
+impl<M> Publisher<M> for WeakPublisher<M>
+{
+    fn publish(
+        &self,
+        message: &M,
+    )
+    {
+        self.publish_and_prune(message);
+    }
+}
@@ -0,0 +1,269 @@
+//! Renders a [`StateMachine`]'s states and
+//! transition table as Graphviz DOT or `PlantUML`
+//! state diagrams, so documentation can be
+//! generated from the machine itself.
+//!
+//! States are named by their [`Debug`] output,
+//! and transitions are labelled with their
+//! event's [`Debug`] output, marked `[guarded]`
+//! where a guard applies.  Quotes surrounding
+//! either are dropped, so string states and
+//! events read naturally.  Both renderers escape
+//! backslashes, quotes and newlines in names and
+//! labels.
+
+use super::{
+    machine::Rule,
+    StateMachine,
+};
+use std::{
+    fmt::Debug,
+    hash::Hash,
+};
+
+/// Renders the given [`StateMachine`] as a
+/// Graphviz DOT digraph.  Nodes are given
+/// generated ids, labelled with their state's
+/// name, so states with the same name stay apart.
+/// Composite states become clusters containing
+/// their substates, with a point node standing for
+/// the composite state itself which leads to its
+/// initial substate.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+///
+/// let mut machine = state::StateMachine::new("Off");
+/// machine.transition("Off", "toggle", "On");
+/// machine.transition("On", "toggle", "Off");
+///
+/// assert_eq!(
+///     state::diagram::dot(&machine),
+///     r#"digraph StateMachine {
+///     "[*]" [shape=point];
+///     S0 [label="Off", shape=box, style=rounded];
+///     S1 [label="On", shape=box, style=rounded];
+///     "[*]" -> S0;
+///     S0 -> S1 [label="toggle"];
+///     S1 -> S0 [label="toggle"];
+/// }
+/// "#
+/// );
+/// ```
+pub fn dot<S, E>(machine: &StateMachine<S, E>) -> String
+where
+    S: Clone + Eq + Hash + Debug,
+    E: PartialEq + Debug,
+{
+    let alias = aliases(machine);
+    let mut lines = vec![
+        "digraph StateMachine {".to_string(),
+        "    \"[*]\" [shape=point];".to_string(),
+    ];
+
+    dot_states(machine, None, 1, &alias, &mut lines);
+
+    lines.push(format!("    \"[*]\" -> {};", alias(machine.initial())));
+    for rule in machine.rules() {
+        lines.push(format!(
+            "    {} -> {} [label={}];",
+            alias(rule.from()),
+            alias(rule.to()),
+            quote(&label(rule))
+        ));
+    }
+    lines.push("}".to_string());
+
+    lines.join("\n") + "\n"
+}
+
+/// Renders the given [`StateMachine`] as a
+/// `PlantUML` state diagram.  Composite states
+/// contain their substates, along with an
+/// initial pseudostate leading to their initial
+/// substate.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+///
+/// let mut machine = state::StateMachine::new("Off");
+/// machine.transition("Off", "toggle", "On");
+/// machine.transition("On", "toggle", "Off");
+///
+/// assert_eq!(
+///     state::diagram::plantuml(&machine),
+///     r#"@startuml
+/// state "Off" as S0
+/// state "On" as S1
+/// [*] --> S0
+/// S0 --> S1 : toggle
+/// S1 --> S0 : toggle
+/// @enduml
+/// "#
+/// );
+/// ```
+pub fn plantuml<S, E>(machine: &StateMachine<S, E>) -> String
+where
+    S: Clone + Eq + Hash + Debug,
+    E: PartialEq + Debug,
+{
+    let alias = aliases(machine);
+    let mut lines = vec!["@startuml".to_string()];
+
+    plantuml_states(machine, None, 0, &alias, &mut lines);
+
+    lines.push(format!("[*] --> {}", alias(machine.initial())));
+    for rule in machine.rules() {
+        lines.push(format!(
+            "{} --> {} : {}",
+            alias(rule.from()),
+            alias(rule.to()),
+            escape(&label(rule))
+        ));
+    }
+    lines.push("@enduml".to_string());
+
+    lines.join("\n") + "\n"
+}
+
+fn dot_states<S, E>(
+    machine: &StateMachine<S, E>,
+    parent: Option<&S>,
+    depth: usize,
+    alias: &dyn Fn(&S) -> String,
+    lines: &mut Vec<String>,
+) where
+    S: Clone + Eq + Hash + Debug,
+    E: PartialEq + Debug,
+{
+    let indent = "    ".repeat(depth);
+
+    for state in children(machine, parent) {
+        let id = alias(state);
+        let label = quote(&name(state));
+
+        if is_composite(machine, state) {
+            lines.push(format!("{indent}subgraph cluster_{id} {{"));
+            lines.push(format!("{indent}    label={label};"));
+            lines.push(format!("{indent}    {id} [shape=point];"));
+            dot_states(machine, Some(state), depth + 1, alias, lines);
+            if let Some(initial) = machine.initial_substate_of(state) {
+                lines.push(format!("{indent}    {id} -> {};", alias(initial)));
+            }
+            lines.push(format!("{indent}}}"));
+        } else {
+            lines.push(format!(
+                "{indent}{id} [label={label}, shape=box, style=rounded];"
+            ));
+        }
+    }
+}
+
+fn plantuml_states<S, E>(
+    machine: &StateMachine<S, E>,
+    parent: Option<&S>,
+    depth: usize,
+    alias: &dyn Fn(&S) -> String,
+    lines: &mut Vec<String>,
+) where
+    S: Clone + Eq + Hash + Debug,
+    E: PartialEq + Debug,
+{
+    let indent = "    ".repeat(depth);
+
+    for state in children(machine, parent) {
+        let declaration = format!(
+            "{indent}state {} as {}",
+            quote(&name(state)),
+            alias(state)
+        );
+
+        if is_composite(machine, state) {
+            lines.push(format!("{declaration} {{"));
+            if let Some(initial) = machine.initial_substate_of(state) {
+                lines.push(format!("{indent}    [*] --> {}", alias(initial)));
+            }
+            plantuml_states(machine, Some(state), depth + 1, alias, lines);
+            lines.push(format!("{indent}}}"));
+        } else {
+            lines.push(declaration);
+        }
+    }
+}
+
+/// Names each state `S<n>`, after its position
+/// in [`StateMachine::states`].
+fn aliases<S, E>(machine: &StateMachine<S, E>) -> impl Fn(&S) -> String + '_
+where
+    S: Clone + Eq + Hash + Debug,
+    E: PartialEq,
+{
+    let states = machine.states();
+
+    move |state: &S| {
+        states
+            .iter()
+            .position(|s| *s == state)
+            .map_or_else(|| quote(&name(state)), |index| format!("S{index}"))
+    }
+}
+
+fn children<'a, S, E>(
+    machine: &'a StateMachine<S, E>,
+    parent: Option<&S>,
+) -> Vec<&'a S>
+where
+    S: Clone + Eq + Hash,
+    E: PartialEq,
+{
+    machine
+        .states()
+        .into_iter()
+        .filter(|state| machine.parent(state) == parent)
+        .collect()
+}
+
+fn is_composite<S, E>(
+    machine: &StateMachine<S, E>,
+    state: &S,
+) -> bool
+where
+    S: Clone + Eq + Hash,
+    E: PartialEq,
+{
+    !children(machine, Some(state)).is_empty()
+}
+
+fn name<S>(state: &S) -> String
+where
+    S: Debug,
+{
+    format!("{state:?}").trim_matches('"').to_string()
+}
+
+fn label<S, E>(rule: &Rule<S, E>) -> String
+where
+    E: Debug,
+{
+    let label = name(rule.on());
+
+    if rule.is_guarded() {
+        format!("{label} [guarded]")
+    } else {
+        label
+    }
+}
+
+fn quote(text: &str) -> String
+{
+    format!("\"{}\"", escape(text))
+}
+
+fn escape(text: &str) -> String
+{
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,541 @@
+//! Contains a hierarchical [`StateMachine`],
+//! which changes state in reaction to events.
+
+use crate::prelude::*;
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    hash::Hash,
+};
+
+/// Decides whether a transition may be taken for
+/// a given event.
+pub type Guard<E> = Box<dyn Fn(&E) -> bool>;
+
+struct Node<S>
+{
+    parent: Option<S>,
+    initial: Option<S>,
+    entry: Vec<command::Heap>,
+    exit: Vec<command::Heap>,
+}
+
+impl<S> Default for Node<S>
+{
+    fn default() -> Self
+    {
+        Node {
+            parent: None,
+            initial: None,
+            entry: Vec::new(),
+            exit: Vec::new(),
+        }
+    }
+}
+
+/// A single row of a [`StateMachine`]'s
+/// transition table: while `from`, or one of its
+/// substates, is active, the event `on` moves the
+/// machine to `to`, provided the guard, if any,
+/// allows it.
+pub struct Rule<S, E>
+{
+    from: S,
+    on: E,
+    to: S,
+    guard: Option<Guard<E>>,
+}
+
+impl<S, E> Rule<S, E>
+{
+    /// Gives the state the transition leaves.
+    pub fn from(&self) -> &S
+    {
+        &self.from
+    }
+
+    /// Gives the event which triggers the
+    /// transition.
+    pub fn on(&self) -> &E
+    {
+        &self.on
+    }
+
+    /// Gives the state the transition enters.
+    pub fn to(&self) -> &S
+    {
+        &self.to
+    }
+
+    /// Indicates whether the transition is
+    /// guarded.
+    pub fn is_guarded(&self) -> bool
+    {
+        self.guard.is_some()
+    }
+}
+
+#[allow(clippy::missing_fields_in_debug)]
+impl<S, E> Debug for Rule<S, E>
+where
+    S: Debug,
+    E: Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("Rule")
+            .field("from", &self.from)
+            .field("on", &self.on)
+            .field("to", &self.to)
+            .field("guarded", &self.is_guarded())
+            .finish()
+    }
+}
+
+/// A statechart-style state machine.  States are
+/// identified by values of `S`, and react to
+/// events of type `E` through a table of
+/// transitions, each optionally guarded.
+///
+/// States may be nested: a composite state
+/// delegates to its substates, and is active
+/// whenever one of them is.  The active state
+/// is always a leaf; entering a composite state
+/// continues into its initial substate.  An
+/// event is handled by the first matching
+/// transition of the active leaf, falling back to
+/// its ancestors, innermost first.
+///
+/// Taking a transition runs the exit
+/// [`Command`]s of each state being left,
+/// innermost first, then the entry [`Command`]s
+/// of each state being entered, outermost first.
+/// States shared by the source and target are
+/// neither exited nor entered, except that a
+/// transition from a state to itself or to one
+/// of its own substates leaves and re-enters it.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// enum Player
+/// {
+///     Stopped,
+///     Active,
+///     Playing,
+///     Paused,
+/// }
+///
+/// #[derive(PartialEq)]
+/// enum Button
+/// {
+///     Play,
+///     Pause,
+///     Stop,
+/// }
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let mut machine = state::StateMachine::new(Player::Stopped);
+///
+/// machine.substate(Player::Active, Player::Playing);
+/// machine.substate(Player::Active, Player::Paused);
+/// let entered = log.clone();
+/// machine.on_entry(Player::Active, move || {
+///     entered.borrow_mut().push("powered up");
+/// });
+///
+/// machine.transition(Player::Stopped, Button::Play, Player::Active);
+/// machine.transition(Player::Playing, Button::Pause, Player::Paused);
+/// machine.transition(Player::Paused, Button::Play, Player::Playing);
+/// machine.transition(Player::Active, Button::Stop, Player::Stopped);
+///
+/// machine.start();
+/// assert!(machine.handle(&Button::Play));
+/// assert_eq!(&Player::Playing, machine.state());
+/// assert!(machine.is_in(&Player::Active));
+///
+/// assert!(machine.handle(&Button::Pause));
+/// assert!(!machine.handle(&Button::Pause));
+/// assert!(machine.handle(&Button::Stop));
+/// assert_eq!(&Player::Stopped, machine.state());
+/// assert_eq!(vec!["powered up"], *log.borrow());
+/// ```
+pub struct StateMachine<S, E>
+where
+    S: Clone + Eq + Hash,
+    E: PartialEq,
+{
+    nodes: HashMap<S, Node<S>>,
+    order: Vec<S>,
+    rules: Vec<Rule<S, E>>,
+    initial: S,
+    current: Option<S>,
+}
+
+impl<S, E> StateMachine<S, E>
+where
+    S: Clone + Eq + Hash,
+    E: PartialEq,
+{
+    /// Creates a new [`StateMachine`] which
+    /// starts in the given state.
+    #[must_use]
+    pub fn new(initial: S) -> StateMachine<S, E>
+    {
+        StateMachine {
+            nodes: HashMap::new(),
+            order: Vec::new(),
+            rules: Vec::new(),
+            initial,
+            current: None,
+        }
+    }
+
+    /// Nests `child` within the composite state
+    /// `parent`.  The first substate added to a
+    /// state becomes its initial substate.  If
+    /// `child` was the initial substate of another
+    /// parent, that parent is left without one.
+    ///
+    /// # Panics
+    /// If `parent` is `child` or already nested
+    /// within it.
+    pub fn substate(
+        &mut self,
+        parent: S,
+        child: S,
+    )
+    {
+        assert!(
+            !self.lineage(&parent).contains(&child),
+            "A state cannot be nested within itself."
+        );
+
+        if let Some(previous) = self.parent(&child).cloned() {
+            let previous = self.node_mut(previous);
+            if previous.initial.as_ref() == Some(&child) {
+                previous.initial = None;
+            }
+        }
+
+        self.node_mut(parent.clone())
+            .initial
+            .get_or_insert_with(|| child.clone());
+        self.node_mut(child).parent = Some(parent);
+    }
+
+    /// Makes `child`, which must be nested
+    /// directly within `parent`, the substate
+    /// entered when `parent` is.
+    ///
+    /// # Panics
+    /// If `child` isn't a direct substate of
+    /// `parent`.
+    pub fn initial_substate(
+        &mut self,
+        parent: S,
+        child: S,
+    )
+    {
+        assert!(
+            self.parent(&child) == Some(&parent),
+            "An initial substate must be nested directly within its parent."
+        );
+
+        self.node_mut(parent).initial = Some(child);
+    }
+
+    /// Adds a [`Command`] to run whenever the
+    /// given state is entered.
+    pub fn on_entry<C>(
+        &mut self,
+        state: S,
+        command: C,
+    ) where
+        C: Into<command::Heap>,
+    {
+        self.node_mut(state).entry.push(command.into());
+    }
+
+    /// Adds a [`Command`] to run whenever the
+    /// given state is exited.
+    pub fn on_exit<C>(
+        &mut self,
+        state: S,
+        command: C,
+    ) where
+        C: Into<command::Heap>,
+    {
+        self.node_mut(state).exit.push(command.into());
+    }
+
+    /// Adds a transition from `from` to `to`,
+    /// triggered by the event `on`.
+    pub fn transition(
+        &mut self,
+        from: S,
+        on: E,
+        to: S,
+    )
+    {
+        self.rules.push(Rule {
+            from,
+            on,
+            to,
+            guard: None,
+        });
+    }
+
+    /// Adds a transition from `from` to `to`,
+    /// triggered by the event `on` only when
+    /// `guard` allows it.  Transitions are tried
+    /// in the order they were added, so an
+    /// unguarded fallback should be added last.
+    pub fn guarded_transition<G>(
+        &mut self,
+        from: S,
+        on: E,
+        to: S,
+        guard: G,
+    ) where
+        G: Fn(&E) -> bool + 'static,
+    {
+        self.rules.push(Rule {
+            from,
+            on,
+            to,
+            guard: Some(Box::new(guard)),
+        });
+    }
+
+    /// Enters the initial state, running entry
+    /// [`Command`]s from the outermost state
+    /// inward.  Does nothing if already started.
+    pub fn start(&mut self)
+    {
+        if self.current.is_some() {
+            return;
+        }
+
+        let initial = self.initial.clone();
+        for state in self.lineage(&initial).iter().rev() {
+            self.enter(state);
+        }
+        self.current = Some(self.descend(initial));
+    }
+
+    /// Delivers an event to the active states,
+    /// taking the first matching transition,
+    /// giving back whether one was taken.  Starts
+    /// the machine first, if necessary.
+    pub fn handle(
+        &mut self,
+        event: &E,
+    ) -> bool
+    {
+        self.start();
+
+        let active = self.lineage(self.state());
+        let Some((from, to)) = active.iter().find_map(|state| {
+            self.rules
+                .iter()
+                .find(|rule| {
+                    rule.from == *state
+                        && rule.on == *event
+                        && rule.guard.as_ref().is_none_or(|guard| guard(event))
+                })
+                .map(|rule| (rule.from.clone(), rule.to.clone()))
+        }) else {
+            return false;
+        };
+
+        let target = self.lineage(&to);
+        let common = self
+            .lineage(&from)
+            .into_iter()
+            .skip(1)
+            .find(|state| target[1..].contains(state));
+        let outside = |state: &&S| Some(*state) != common.as_ref();
+
+        for state in active.iter().take_while(outside) {
+            self.exit(state);
+        }
+        for state in target
+            .iter()
+            .take_while(outside)
+            .collect::<Vec<_>>()
+            .iter()
+            .rev()
+        {
+            self.enter(state);
+        }
+        self.current = Some(self.descend(to));
+
+        true
+    }
+
+    /// Gives the active leaf state, or the
+    /// initial state if not yet started.
+    pub fn state(&self) -> &S
+    {
+        self.current.as_ref().unwrap_or(&self.initial)
+    }
+
+    /// Indicates whether the given state is the
+    /// active state or one of its ancestors.
+    pub fn is_in(
+        &self,
+        state: &S,
+    ) -> bool
+    {
+        self.lineage(self.state()).contains(state)
+    }
+
+    /// Gives the composite state the given state
+    /// is nested within, if any.
+    pub fn parent(
+        &self,
+        state: &S,
+    ) -> Option<&S>
+    {
+        self.nodes.get(state)?.parent.as_ref()
+    }
+
+    /// Gives the substate entered when the given
+    /// composite state is, if any.
+    pub fn initial_substate_of(
+        &self,
+        state: &S,
+    ) -> Option<&S>
+    {
+        self.nodes.get(state)?.initial.as_ref()
+    }
+
+    /// Gives the state the machine starts in.
+    pub fn initial(&self) -> &S
+    {
+        &self.initial
+    }
+
+    /// Gives every state the machine knows of:
+    /// the initial state, then those configured
+    /// or nested, then those only named by
+    /// transitions, each in the order first
+    /// mentioned.
+    pub fn states(&self) -> Vec<&S>
+    {
+        let mut states: Vec<&S> = Vec::new();
+        let rule_states =
+            self.rules.iter().flat_map(|rule| [&rule.from, &rule.to]);
+
+        for state in std::iter::once(&self.initial)
+            .chain(&self.order)
+            .chain(rule_states)
+        {
+            if !states.contains(&state) {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+
+    /// Gives the transition table, in the order
+    /// transitions were added.
+    pub fn rules(&self) -> &[Rule<S, E>]
+    {
+        &self.rules
+    }
+
+    fn node_mut(
+        &mut self,
+        state: S,
+    ) -> &mut Node<S>
+    {
+        if !self.nodes.contains_key(&state) {
+            self.order.push(state.clone());
+        }
+
+        self.nodes.entry(state).or_default()
+    }
+
+    /// Gives the given state followed by each of
+    /// its ancestors, innermost first.
+    fn lineage(
+        &self,
+        state: &S,
+    ) -> Vec<S>
+    {
+        let mut lineage = vec![state.clone()];
+        while let Some(parent) = self.parent(&lineage[lineage.len() - 1]) {
+            lineage.push(parent.clone());
+        }
+
+        lineage
+    }
+
+    /// Enters initial substates, starting below
+    /// the given state, giving back the leaf.
+    fn descend(
+        &self,
+        mut state: S,
+    ) -> S
+    {
+        while let Some(child) =
+            self.nodes.get(&state).and_then(|node| node.initial.clone())
+        {
+            self.enter(&child);
+            state = child;
+        }
+
+        state
+    }
+
+    fn enter(
+        &self,
+        state: &S,
+    )
+    {
+        for command in self.nodes.get(state).into_iter().flat_map(|n| &n.entry)
+        {
+            command.execute();
+        }
+    }
+
+    fn exit(
+        &self,
+        state: &S,
+    )
+    {
+        for command in self.nodes.get(state).into_iter().flat_map(|n| &n.exit) {
+            command.execute();
+        }
+    }
+}
+
+impl<S, E> Debug for StateMachine<S, E>
+where
+    S: Clone + Eq + Hash + Debug,
+    E: PartialEq + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("StateMachine")
+            .field("state", self.state())
+            .field("started", &self.current.is_some())
+            .field("rules", &self.rules)
+            .finish_non_exhaustive()
+    }
+}
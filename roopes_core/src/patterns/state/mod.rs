@@ -7,10 +7,30 @@
         "src/patterns/state/state.svg"
 )))]
 //! This module implements the State pattern.
+//! Alongside the minimal [`Context`] and
+//! [`State`] traits, [`StateMachine`] provides
+//! statechart-style machines driven by typed
+//! events, with guarded transitions, entry and
+//! exit [`crate::prelude::Command`]s, and nested
+//! states.  [`ObservableContext`] and
+//! [`ObservableMachine`] publish each
+//! [`Transition`] they make, and [`diagram`]
+//! renders a [`StateMachine`] as DOT or
+//! `PlantUML`.
 //!
 //! ![state diagram][state-diagram]
 
+pub mod diagram;
+pub mod machine;
+pub mod observable_context;
+pub mod observable_machine;
 pub mod simple;
+pub mod transition;
+
+pub use machine::StateMachine;
+pub use observable_context::ObservableContext;
+pub use observable_machine::ObservableMachine;
+pub use transition::Transition;
 
 /// This trait holds the active state, and acts as
 /// a persistent handle for the state machine,
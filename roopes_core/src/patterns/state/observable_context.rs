@@ -0,0 +1,187 @@
+//! Contains an [`ObservableContext`], which
+//! publishes a [`Transition`] whenever it changes
+//! state.
+
+use super::{
+    Context,
+    State,
+    Transition,
+};
+use crate::prelude::{
+    publisher_subscriber::{
+        heap,
+        VecPublisher,
+    },
+    *,
+};
+use std::fmt::Debug;
+
+/// A [`Context`] which, like
+/// [`super::simple::SimpleContext`], stores the
+/// current [`State`], and which publishes a
+/// [`Transition`] to its delegate [`Publisher`]
+/// each time [`Context::handle`] changes the
+/// state.  A [`State`] which executes into an
+/// equal state publishes nothing.
+/// It is also a [`Publisher`] of
+/// [`Transition`]s itself, so it may be used
+/// wherever one is expected.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Light
+/// {
+///     On,
+///     Off,
+/// }
+///
+/// impl State for Light
+/// {
+///     fn execute(&self) -> Self
+///     {
+///         match self {
+///             | Light::On => Light::Off,
+///             | Light::Off => Light::On,
+///         }
+///     }
+/// }
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let mut context = state::ObservableContext::new(Light::Off);
+///
+/// let log_ext = log.clone();
+/// context.publisher_mut().attach(
+///     SubscribingHandler::new(handler::Lambda::new(
+///         move |t: &state::Transition<Light>| {
+///             log_ext
+///                 .borrow_mut()
+///                 .push((t.old().clone(), t.new_state().clone()));
+///         },
+///     ))
+///     .into_heap(),
+/// );
+///
+/// context.handle();
+/// assert_eq!(&Light::On, context.get_state());
+/// assert_eq!(vec![(Light::Off, Light::On)], *log.borrow());
+/// ```
+pub struct ObservableContext<
+    S,
+    P = VecPublisher<Transition<S>, heap::Subscriber<Transition<S>>>,
+> where
+    S: State + Clone,
+    P: Publisher<Transition<S>>,
+{
+    state: S,
+    publisher: P,
+}
+
+impl<S> ObservableContext<S>
+where
+    S: State + Clone,
+{
+    /// Creates a new [`ObservableContext`] with a
+    /// given starting [`State`], publishing to a
+    /// [`VecPublisher`] with no subscribers.
+    #[must_use]
+    pub fn new(starting_state: S) -> ObservableContext<S>
+    {
+        Self::with_publisher(starting_state, VecPublisher::default())
+    }
+}
+
+impl<S, P> ObservableContext<S, P>
+where
+    S: State + Clone,
+    P: Publisher<Transition<S>>,
+{
+    /// Creates a new [`ObservableContext`] with a
+    /// given starting [`State`], which publishes
+    /// its [`Transition`]s to the given
+    /// [`Publisher`].
+    pub fn with_publisher(
+        starting_state: S,
+        publisher: P,
+    ) -> ObservableContext<S, P>
+    {
+        ObservableContext {
+            state: starting_state,
+            publisher,
+        }
+    }
+
+    /// Gets the current [`State`].
+    pub fn get_state(&self) -> &S
+    {
+        &self.state
+    }
+
+    /// Gives the delegate [`Publisher`].
+    pub fn publisher(&self) -> &P
+    {
+        &self.publisher
+    }
+
+    /// Gives the delegate [`Publisher`] mutably,
+    /// e.g.: to attach subscribers.
+    pub fn publisher_mut(&mut self) -> &mut P
+    {
+        &mut self.publisher
+    }
+}
+
+impl<S, P> Context<S> for ObservableContext<S, P>
+where
+    S: State + Clone + PartialEq,
+    P: Publisher<Transition<S>>,
+{
+    fn handle(&mut self)
+    {
+        let new = self.state.execute();
+        if new == self.state {
+            return;
+        }
+
+        let old = std::mem::replace(&mut self.state, new.clone());
+
+        self.publisher.publish(&Transition::new(old, new, ()));
+    }
+}
+
+impl<S, P> Publisher<Transition<S>> for ObservableContext<S, P>
+where
+    S: State + Clone,
+    P: Publisher<Transition<S>>,
+{
+    fn publish(
+        &self,
+        message: &Transition<S>,
+    )
+    {
+        self.publisher.publish(message);
+    }
+}
+
+impl<S, P> Debug for ObservableContext<S, P>
+where
+    S: State + Clone + Debug,
+    P: Publisher<Transition<S>> + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("ObservableContext")
+            .field("state", &self.state)
+            .field("publisher", &self.publisher)
+            .finish()
+    }
+}
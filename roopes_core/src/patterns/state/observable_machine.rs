@@ -0,0 +1,196 @@
+//! Contains an [`ObservableMachine`], which
+//! publishes a [`Transition`] whenever its
+//! [`StateMachine`] takes one.
+
+use super::{
+    StateMachine,
+    Transition,
+};
+use crate::prelude::{
+    publisher_subscriber::{
+        heap,
+        VecPublisher,
+    },
+    *,
+};
+use std::{
+    fmt::Debug,
+    hash::Hash,
+};
+
+/// Wraps a [`StateMachine`], publishing a
+/// [`Transition`] from the old active state to
+/// the new one, along with the triggering event,
+/// to its delegate [`Publisher`] each time
+/// [`ObservableMachine::handle`] takes a
+/// transition.  It is also a [`Publisher`] of
+/// [`Transition`]s itself.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// enum Door
+/// {
+///     Open,
+///     Closed,
+/// }
+///
+/// let mut machine = state::StateMachine::new(Door::Open);
+/// machine.transition(Door::Open, "close", Door::Closed);
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let mut observable = state::ObservableMachine::new(machine);
+///
+/// let log_ext = log.clone();
+/// observable.publisher_mut().attach(
+///     SubscribingHandler::new(handler::Lambda::new(
+///         move |t: &state::Transition<Door, &str>| {
+///             log_ext.borrow_mut().push(t.clone());
+///         },
+///     ))
+///     .into_heap(),
+/// );
+///
+/// observable.handle(&"close");
+/// observable.handle(&"close");
+///
+/// assert_eq!(
+///     vec![state::Transition::new(Door::Open, Door::Closed, "close")],
+///     *log.borrow()
+/// );
+/// ```
+pub struct ObservableMachine<
+    S,
+    E,
+    P = VecPublisher<Transition<S, E>, heap::Subscriber<Transition<S, E>>>,
+> where
+    S: Clone + Eq + Hash,
+    E: Clone + PartialEq,
+    P: Publisher<Transition<S, E>>,
+{
+    machine: StateMachine<S, E>,
+    publisher: P,
+}
+
+impl<S, E> ObservableMachine<S, E>
+where
+    S: Clone + Eq + Hash,
+    E: Clone + PartialEq,
+{
+    /// Creates a new [`ObservableMachine`] around
+    /// the given [`StateMachine`], publishing to
+    /// a [`VecPublisher`] with no subscribers.
+    #[must_use]
+    pub fn new(machine: StateMachine<S, E>) -> ObservableMachine<S, E>
+    {
+        Self::with_publisher(machine, VecPublisher::default())
+    }
+}
+
+impl<S, E, P> ObservableMachine<S, E, P>
+where
+    S: Clone + Eq + Hash,
+    E: Clone + PartialEq,
+    P: Publisher<Transition<S, E>>,
+{
+    /// Creates a new [`ObservableMachine`] around
+    /// the given [`StateMachine`], which
+    /// publishes its [`Transition`]s to the given
+    /// [`Publisher`].
+    pub fn with_publisher(
+        machine: StateMachine<S, E>,
+        publisher: P,
+    ) -> ObservableMachine<S, E, P>
+    {
+        ObservableMachine { machine, publisher }
+    }
+
+    /// Delivers an event to the [`StateMachine`],
+    /// publishing a [`Transition`] if one was
+    /// taken, and giving back whether it was.
+    pub fn handle(
+        &mut self,
+        event: &E,
+    ) -> bool
+    {
+        self.machine.start();
+
+        let old = self.machine.state().clone();
+        if !self.machine.handle(event) {
+            return false;
+        }
+
+        self.publisher.publish(&Transition::new(
+            old,
+            self.machine.state().clone(),
+            event.clone(),
+        ));
+
+        true
+    }
+
+    /// Gives the wrapped [`StateMachine`].
+    pub fn machine(&self) -> &StateMachine<S, E>
+    {
+        &self.machine
+    }
+
+    /// Gives the wrapped [`StateMachine`]
+    /// mutably, e.g.: to add transitions.
+    pub fn machine_mut(&mut self) -> &mut StateMachine<S, E>
+    {
+        &mut self.machine
+    }
+
+    /// Gives the delegate [`Publisher`].
+    pub fn publisher(&self) -> &P
+    {
+        &self.publisher
+    }
+
+    /// Gives the delegate [`Publisher`] mutably,
+    /// e.g.: to attach subscribers.
+    pub fn publisher_mut(&mut self) -> &mut P
+    {
+        &mut self.publisher
+    }
+}
+
+impl<S, E, P> Publisher<Transition<S, E>> for ObservableMachine<S, E, P>
+where
+    S: Clone + Eq + Hash,
+    E: Clone + PartialEq,
+    P: Publisher<Transition<S, E>>,
+{
+    fn publish(
+        &self,
+        message: &Transition<S, E>,
+    )
+    {
+        self.publisher.publish(message);
+    }
+}
+
+impl<S, E, P> Debug for ObservableMachine<S, E, P>
+where
+    S: Clone + Eq + Hash + Debug,
+    E: Clone + PartialEq + Debug,
+    P: Publisher<Transition<S, E>> + Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("ObservableMachine")
+            .field("machine", &self.machine)
+            .field("publisher", &self.publisher)
+            .finish()
+    }
+}
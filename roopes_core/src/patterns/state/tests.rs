@@ -4,7 +4,7 @@ use std::{
     rc::Rc,
 };
 
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
 enum TS
 {
     A,
@@ -37,3 +37,396 @@ fn simple_state()
     ctx.handle();
     assert!(ctx.get_state() == &TS::A);
 }
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Door
+{
+    Closed,
+    Open,
+    Locked,
+    Outer,
+    Inner,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum DoorEvent
+{
+    Push,
+    Pull,
+    Lock(u32),
+    Reset,
+}
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn logged_door(log: &Log) -> state::StateMachine<Door, DoorEvent>
+{
+    let mut machine = state::StateMachine::new(Door::Closed);
+
+    machine.substate(Door::Outer, Door::Closed);
+    machine.substate(Door::Outer, Door::Inner);
+    machine.substate(Door::Inner, Door::Open);
+    machine.substate(Door::Inner, Door::Locked);
+    machine.initial_substate(Door::Outer, Door::Inner);
+
+    for state in [
+        Door::Closed,
+        Door::Open,
+        Door::Locked,
+        Door::Outer,
+        Door::Inner,
+    ] {
+        let (entered, exited) = (log.clone(), log.clone());
+        let (entry_name, exit_name) =
+            (format!("+{state:?}"), format!("-{state:?}"));
+        machine.on_entry(state.clone(), move || {
+            entered.borrow_mut().push(entry_name.clone());
+        });
+        machine.on_exit(state, move || {
+            exited.borrow_mut().push(exit_name.clone());
+        });
+    }
+
+    machine.transition(Door::Closed, DoorEvent::Push, Door::Open);
+    machine.transition(Door::Open, DoorEvent::Pull, Door::Closed);
+    machine.guarded_transition(
+        Door::Open,
+        DoorEvent::Lock(1234),
+        Door::Locked,
+        |_| true,
+    );
+    machine.transition(Door::Inner, DoorEvent::Push, Door::Inner);
+    machine.transition(Door::Outer, DoorEvent::Reset, Door::Outer);
+
+    machine
+}
+
+#[test]
+fn state_machine_entry_and_exit_order()
+{
+    let log = Log::default();
+    let mut machine = logged_door(&log);
+
+    machine.start();
+    assert_eq!(&Door::Closed, machine.state());
+    assert_eq!(vec!["+Outer", "+Closed"], log.take());
+
+    assert!(machine.handle(&DoorEvent::Push));
+    assert_eq!(&Door::Open, machine.state());
+    assert!(machine.is_in(&Door::Inner));
+    assert_eq!(vec!["-Closed", "+Inner", "+Open"], log.take());
+
+    // Handled by the ancestor Inner, which is left and re-entered.
+    assert!(machine.handle(&DoorEvent::Push));
+    assert_eq!(&Door::Open, machine.state());
+    assert_eq!(vec!["-Open", "-Inner", "+Inner", "+Open"], log.take());
+
+    // Handled by the outermost state, entering its initial substates.
+    assert!(machine.handle(&DoorEvent::Reset));
+    assert_eq!(&Door::Open, machine.state());
+    assert_eq!(
+        vec!["-Open", "-Inner", "-Outer", "+Outer", "+Inner", "+Open"],
+        log.take()
+    );
+
+    assert!(!machine.handle(&DoorEvent::Lock(1)));
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn state_machine_guards()
+{
+    let mut machine = state::StateMachine::new(Door::Open);
+    machine.guarded_transition(
+        Door::Open,
+        DoorEvent::Pull,
+        Door::Locked,
+        |_| false,
+    );
+    machine.transition(Door::Open, DoorEvent::Pull, Door::Closed);
+    machine.guarded_transition(
+        Door::Closed,
+        DoorEvent::Push,
+        Door::Open,
+        |e| *e == DoorEvent::Push,
+    );
+
+    assert!(!machine.handle(&DoorEvent::Push));
+    assert!(machine.handle(&DoorEvent::Pull));
+    assert_eq!(&Door::Closed, machine.state());
+    assert!(machine.handle(&DoorEvent::Push));
+    assert_eq!(&Door::Open, machine.state());
+
+    assert_eq!(3, machine.rules().len());
+    assert!(machine.rules()[0].is_guarded());
+    assert!(!machine.rules()[1].is_guarded());
+}
+
+#[test]
+#[should_panic(expected = "A state cannot be nested within itself.")]
+fn state_machine_rejects_cycles()
+{
+    let mut machine = state::StateMachine::<Door, DoorEvent>::new(Door::Open);
+    machine.substate(Door::Outer, Door::Inner);
+    machine.substate(Door::Inner, Door::Outer);
+}
+
+#[test]
+#[should_panic(
+    expected = "An initial substate must be nested directly within its parent."
+)]
+fn state_machine_rejects_initial_substate_outside_parent()
+{
+    let mut machine = state::StateMachine::<Door, DoorEvent>::new(Door::Open);
+    machine.substate(Door::Outer, Door::Inner);
+    machine.initial_substate(Door::Inner, Door::Outer);
+}
+
+#[test]
+#[should_panic(
+    expected = "An initial substate must be nested directly within its parent."
+)]
+fn state_machine_rejects_self_initial_substate()
+{
+    let mut machine = state::StateMachine::<Door, DoorEvent>::new(Door::Open);
+    machine.substate(Door::Outer, Door::Inner);
+    machine.initial_substate(Door::Outer, Door::Outer);
+}
+
+#[test]
+fn state_machine_reparenting_clears_initial_substate()
+{
+    let mut machine = state::StateMachine::<Door, DoorEvent>::new(Door::Outer);
+    machine.substate(Door::Outer, Door::Inner);
+    machine.substate(Door::Outer, Door::Open);
+    machine.substate(Door::Closed, Door::Inner);
+
+    assert_eq!(None, machine.initial_substate_of(&Door::Outer));
+    assert_eq!(
+        Some(&Door::Inner),
+        machine.initial_substate_of(&Door::Closed)
+    );
+
+    machine.start();
+    assert_eq!(&Door::Outer, machine.state());
+}
+
+#[test]
+fn observable_context_publishes_transitions()
+{
+    let transitions = Rc::new(RefCell::new(Vec::new()));
+    let mut ctx = state::ObservableContext::new(TS::A);
+
+    let transitions_ext = transitions.clone();
+    ctx.publisher_mut().attach(
+        SubscribingHandler::new(handler::Lambda::new(
+            move |t: &state::Transition<TS>| {
+                transitions_ext
+                    .borrow_mut()
+                    .push(*t.old() == TS::A && *t.new_state() == TS::B);
+            },
+        ))
+        .into_heap(),
+    );
+
+    ctx.handle();
+    ctx.handle();
+    assert!(ctx.get_state() == &TS::A);
+    assert_eq!(vec![true, false], *transitions.borrow());
+}
+
+#[derive(Clone, PartialEq)]
+struct Stuck;
+
+impl State for Stuck
+{
+    fn execute(&self) -> Self
+    {
+        Stuck
+    }
+}
+
+#[test]
+fn observable_context_skips_self_transitions()
+{
+    let transitions = Rc::new(RefCell::new(0));
+    let mut ctx = state::ObservableContext::new(Stuck);
+
+    let transitions_ext = transitions.clone();
+    ctx.publisher_mut().attach(
+        SubscribingHandler::new(handler::Lambda::new(
+            move |_: &state::Transition<Stuck>| {
+                *transitions_ext.borrow_mut() += 1;
+            },
+        ))
+        .into_heap(),
+    );
+
+    ctx.handle();
+    ctx.handle();
+    assert_eq!(0, *transitions.borrow());
+}
+
+#[test]
+fn observable_machine_publishes_taken_transitions()
+{
+    let log = Log::default();
+    let transitions = Rc::new(RefCell::new(Vec::new()));
+    let mut machine = state::ObservableMachine::new(logged_door(&log));
+
+    let transitions_ext = transitions.clone();
+    machine.publisher_mut().attach(
+        SubscribingHandler::new(handler::Lambda::new(
+            move |t: &state::Transition<Door, DoorEvent>| {
+                transitions_ext.borrow_mut().push(format!(
+                    "{:?} -{:?}-> {:?}",
+                    t.old(),
+                    t.event(),
+                    t.new_state()
+                ));
+            },
+        ))
+        .into_heap(),
+    );
+
+    assert!(machine.handle(&DoorEvent::Push));
+    assert!(!machine.handle(&DoorEvent::Lock(1)));
+    assert!(machine.handle(&DoorEvent::Lock(1234)));
+
+    assert_eq!(&Door::Locked, machine.machine().state());
+    assert_eq!(
+        vec!["Closed -Push-> Open", "Open -Lock(1234)-> Locked"],
+        *transitions.borrow()
+    );
+}
+
+#[test]
+fn state_machine_diagrams()
+{
+    let mut machine = state::StateMachine::new(Door::Closed);
+    machine.substate(Door::Outer, Door::Closed);
+    machine.substate(Door::Outer, Door::Open);
+    machine.transition(Door::Closed, DoorEvent::Push, Door::Open);
+    machine.guarded_transition(
+        Door::Open,
+        DoorEvent::Lock(7),
+        Door::Locked,
+        |_| true,
+    );
+
+    assert_eq!(
+        state::diagram::dot(&machine),
+        r#"digraph StateMachine {
+    "[*]" [shape=point];
+    subgraph cluster_S1 {
+        label="Outer";
+        S1 [shape=point];
+        S0 [label="Closed", shape=box, style=rounded];
+        S2 [label="Open", shape=box, style=rounded];
+        S1 -> S0;
+    }
+    S3 [label="Locked", shape=box, style=rounded];
+    "[*]" -> S0;
+    S0 -> S2 [label="Push"];
+    S2 -> S3 [label="Lock(7) [guarded]"];
+}
+"#
+    );
+
+    assert_eq!(
+        state::diagram::plantuml(&machine),
+        r#"@startuml
+state "Outer" as S1 {
+    [*] --> S0
+    state "Closed" as S0
+    state "Open" as S2
+}
+state "Locked" as S3
+[*] --> S0
+S0 --> S2 : Push
+S2 --> S3 : Lock(7) [guarded]
+@enduml
+"#
+    );
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct Caption(&'static str);
+
+impl std::fmt::Debug for Caption
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.write_str(self.0)
+    }
+}
+
+#[test]
+fn state_machine_diagrams_escape_labels()
+{
+    let mut machine = state::StateMachine::new(Caption("Say \"hi\" twice"));
+    machine.transition(
+        Caption("Say \"hi\" twice"),
+        Caption("wave\nback"),
+        Caption("Done"),
+    );
+
+    assert_eq!(
+        state::diagram::dot(&machine),
+        r#"digraph StateMachine {
+    "[*]" [shape=point];
+    S0 [label="Say \"hi\" twice", shape=box, style=rounded];
+    S1 [label="Done", shape=box, style=rounded];
+    "[*]" -> S0;
+    S0 -> S1 [label="wave\nback"];
+}
+"#
+    );
+
+    assert_eq!(
+        state::diagram::plantuml(&machine),
+        r#"@startuml
+state "Say \"hi\" twice" as S0
+state "Done" as S1
+[*] --> S0
+S0 --> S1 : wave\nback
+@enduml
+"#
+    );
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct Twin(u8);
+
+impl std::fmt::Debug for Twin
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.write_str("Twin")
+    }
+}
+
+#[test]
+fn state_machine_diagrams_keep_same_named_states_apart()
+{
+    let mut machine = state::StateMachine::new(Twin(0));
+    machine.transition(Twin(0), "swap", Twin(1));
+
+    assert_eq!(
+        state::diagram::dot(&machine),
+        r#"digraph StateMachine {
+    "[*]" [shape=point];
+    S0 [label="Twin", shape=box, style=rounded];
+    S1 [label="Twin", shape=box, style=rounded];
+    "[*]" -> S0;
+    S0 -> S1 [label="swap"];
+}
+"#
+    );
+}
@@ -0,0 +1,47 @@
+//! Contains [`Transition`], which records a
+//! change of state.
+
+/// Records a change from one state to another,
+/// along with the event which triggered it.
+/// State changes which aren't triggered by an
+/// event, such as those of an
+/// [`super::ObservableContext`], carry `()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Transition<S, E = ()>
+{
+    old: S,
+    new: S,
+    event: E,
+}
+
+impl<S, E> Transition<S, E>
+{
+    /// Creates a new [`Transition`].
+    pub fn new(
+        old: S,
+        new: S,
+        event: E,
+    ) -> Transition<S, E>
+    {
+        Transition { old, new, event }
+    }
+
+    /// Gives the state which was left.
+    pub fn old(&self) -> &S
+    {
+        &self.old
+    }
+
+    /// Gives the state which was entered.
+    pub fn new_state(&self) -> &S
+    {
+        &self.new
+    }
+
+    /// Gives the event which triggered the
+    /// change.
+    pub fn event(&self) -> &E
+    {
+        &self.event
+    }
+}
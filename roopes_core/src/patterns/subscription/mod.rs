@@ -0,0 +1,114 @@
+//! Provides [`Subscription`], a token returned
+//! when a listener is attached to a subject or
+//! publisher which detaches the listener again
+//! when dropped.  Listeners attached this way
+//! don't need to implement [`Eq`] to be
+//! detached.
+//!
+//! Subscribing, and dropping a [`Subscription`],
+//! are the only ways to change a subject's or
+//! publisher's listeners from within one of its
+//! listeners.
+//! [`super::observer::AttachableSubject::attach`],
+//! [`super::observer::DetachableSubject::detach`]
+//! and their publisher counterparts take the
+//! subject or publisher mutably, so they can't be
+//! reached while it is dispatching.
+
+pub(crate) mod registry;
+
+use std::fmt::Debug;
+
+/// Keeps a listener attached for as long as it
+/// is alive.  Dropping the [`Subscription`], or
+/// calling [`Subscription::detach`], detaches the
+/// listener.  If the subject or publisher has
+/// already been dropped, nothing happens.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::{
+///     cell::RefCell,
+///     rc::Rc,
+/// };
+///
+/// let count = Rc::new(RefCell::new(0));
+/// let subject = observer::VecSubject::default();
+///
+/// let subscription =
+///     subject.subscribe(ObservingCommand::new(command::Heap::from({
+///         let count = count.clone();
+///         move || *count.borrow_mut() += 1
+///     })));
+///
+/// subject.notify();
+/// drop(subscription);
+/// subject.notify();
+///
+/// assert_eq!(1, *count.borrow());
+/// ```
+#[must_use = "dropping a Subscription immediately detaches its listener"]
+pub struct Subscription
+{
+    detach: Option<Box<dyn FnOnce()>>,
+}
+
+impl Subscription
+{
+    /// Creates a [`Subscription`] which calls the
+    /// given closure to detach its listener.
+    pub fn new<D>(detach: D) -> Subscription
+    where
+        D: FnOnce() + 'static,
+    {
+        Subscription {
+            detach: Some(Box::new(detach)),
+        }
+    }
+
+    /// Detaches the listener immediately.
+    pub fn detach(self)
+    {
+        drop(self);
+    }
+
+    /// Consumes the [`Subscription`] without
+    /// detaching the listener, which then stays
+    /// attached for the lifetime of the subject or
+    /// publisher.
+    pub fn forget(mut self)
+    {
+        self.detach = None;
+    }
+}
+
+impl Drop for Subscription
+{
+    fn drop(&mut self)
+    {
+        if let Some(detach) = self.detach.take() {
+            detach();
+        }
+    }
+}
+
+impl Debug for Subscription
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        f.debug_struct("Subscription")
+            .field("attached", &self.detach.is_some())
+            .finish()
+    }
+}
+
+/// Exposes the [`Subscription`] type at the
+/// library level.
+pub mod prelude
+{
+    pub use super::Subscription;
+}
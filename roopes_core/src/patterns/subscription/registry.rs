@@ -0,0 +1,395 @@
+//! Provides [`Registry`], the listener storage
+//! shared by single-threaded subjects and
+//! publishers and the [`Subscription`]s they hand
+//! out.  A [`Registry`] may be changed while it is
+//! dispatching: changes made from within a
+//! listener are deferred until the outermost
+//! dispatch finishes.  Only [`Registry::attach`]
+//! and [`Registry::detach`] are deferred: the
+//! owning subject's or publisher's `&mut self`
+//! methods reach the storage directly, so they
+//! can't be called from a listener anyway.
+
+use super::Subscription;
+use std::{
+    cell::{
+        Cell,
+        OnceCell,
+        Ref,
+        RefCell,
+    },
+    collections::HashMap,
+    hash::Hash,
+    sync::{
+        Arc,
+        Mutex,
+        PoisonError,
+    },
+};
+
+/// A collection of listeners, each identified by
+/// the id it was inserted with.
+pub(crate) trait Storage
+{
+    /// The listener type.
+    type Item;
+
+    /// Adds a listener under the given id.
+    fn insert(
+        &mut self,
+        id: usize,
+        item: Self::Item,
+    );
+
+    /// Removes the listener inserted under the
+    /// given id, if it is still present.
+    fn remove(
+        &mut self,
+        id: usize,
+    );
+
+    /// Visits every listener, in the storage's
+    /// order, along with every id it was
+    /// inserted under.
+    fn for_each(
+        &self,
+        f: &mut dyn FnMut(&[usize], &Self::Item),
+    );
+}
+
+/// Keeps listeners in attachment order.
+impl<T> Storage for Vec<(usize, T)>
+{
+    type Item = T;
+
+    fn insert(
+        &mut self,
+        id: usize,
+        item: T,
+    )
+    {
+        self.push((id, item));
+    }
+
+    fn remove(
+        &mut self,
+        id: usize,
+    )
+    {
+        self.retain(|(i, _)| *i != id);
+    }
+
+    fn for_each(
+        &self,
+        f: &mut dyn FnMut(&[usize], &T),
+    )
+    {
+        for (id, item) in self {
+            f(std::slice::from_ref(id), item);
+        }
+    }
+}
+
+/// Keeps one of each equal listener.  Inserting a
+/// listener equal to one already present records
+/// the new id against the existing listener, which
+/// stays present until every id recorded against
+/// it is removed.
+impl<T> Storage for HashMap<T, Vec<usize>>
+where
+    T: Eq + Hash,
+{
+    type Item = T;
+
+    fn insert(
+        &mut self,
+        id: usize,
+        item: T,
+    )
+    {
+        self.entry(item).or_default().push(id);
+    }
+
+    fn remove(
+        &mut self,
+        id: usize,
+    )
+    {
+        self.retain(|_, ids| {
+            ids.retain(|i| *i != id);
+            !ids.is_empty()
+        });
+    }
+
+    fn for_each(
+        &self,
+        f: &mut dyn FnMut(&[usize], &T),
+    )
+    {
+        for (item, ids) in self {
+            f(ids, item);
+        }
+    }
+}
+
+enum Pending<T>
+{
+    Attach(usize, T),
+    Detach(usize),
+}
+
+/// Wraps a [`Storage`] so that it may be attached
+/// to and detached from while it is dispatching.
+/// Listeners attached during a dispatch first see
+/// the next one.  Listeners detached during a
+/// dispatch see nothing further, including the
+/// rest of the dispatch in progress.
+///
+/// A [`Registry`] is [`Send`] whenever its
+/// storage is.  The only state it shares with the
+/// [`Subscription`]s it hands out is the list of
+/// ids they have unsubscribed, which is created by
+/// the first [`Registry::subscribe`].
+pub(crate) struct Registry<S>
+where
+    S: Storage,
+{
+    storage: RefCell<S>,
+    pending: RefCell<Vec<Pending<S::Item>>>,
+    dispatching: Cell<usize>,
+    next_id: Cell<usize>,
+    unsubscribed: OnceCell<Arc<Mutex<Vec<usize>>>>,
+}
+
+impl<S> Registry<S>
+where
+    S: Storage,
+{
+    /// Creates a [`Registry`] from the given
+    /// storage, whose ids must all be lower than
+    /// `next_id`.
+    pub(crate) fn new(
+        storage: S,
+        next_id: usize,
+    ) -> Registry<S>
+    {
+        Registry {
+            storage: RefCell::new(storage),
+            pending: RefCell::new(Vec::new()),
+            dispatching: Cell::new(0),
+            next_id: Cell::new(next_id),
+            unsubscribed: OnceCell::new(),
+        }
+    }
+
+    /// Attaches the listener, giving back its id.
+    pub(crate) fn attach(
+        &self,
+        item: S::Item,
+    ) -> usize
+    {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+
+        if self.dispatching.get() > 0 {
+            self.pending.borrow_mut().push(Pending::Attach(id, item));
+        } else {
+            self.storage.borrow_mut().insert(id, item);
+        }
+
+        id
+    }
+
+    /// Detaches the listener with the given id.
+    pub(crate) fn detach(
+        &self,
+        id: usize,
+    )
+    {
+        if self.dispatching.get() > 0 {
+            self.pending.borrow_mut().push(Pending::Detach(id));
+        } else {
+            self.storage.borrow_mut().remove(id);
+        }
+    }
+
+    /// Gives mutable access to the storage.  Must
+    /// not be called while dispatching, which is
+    /// guaranteed when the caller holds the
+    /// owning subject or publisher mutably.
+    pub(crate) fn storage_mut(&self) -> std::cell::RefMut<'_, S>
+    {
+        self.detach_unsubscribed();
+
+        self.storage.borrow_mut()
+    }
+
+    /// Gives the number of listeners, including
+    /// any detached during a dispatch still in
+    /// progress.
+    pub(crate) fn len(&self) -> usize
+    {
+        self.detach_unsubscribed();
+
+        let mut len = 0;
+        self.storage.borrow().for_each(&mut |_, _| len += 1);
+
+        len
+    }
+
+    /// Gives shared access to the storage, which
+    /// may still hold listeners whose
+    /// [`Subscription`]s were dropped since the
+    /// last dispatch.
+    pub(crate) fn storage(&self) -> Ref<'_, S>
+    {
+        self.storage.borrow()
+    }
+
+    /// Gives one of the ids still attached for
+    /// each listener matching `predicate`.  Unlike
+    /// [`Registry::dispatch`], this only reads the
+    /// storage, so any changes made meanwhile
+    /// aren't deferred.
+    pub(crate) fn find(
+        &self,
+        predicate: &mut dyn FnMut(&S::Item) -> bool,
+    ) -> Vec<usize>
+    {
+        self.detach_unsubscribed();
+
+        let mut found = Vec::new();
+        self.storage.borrow().for_each(&mut |ids, item| {
+            if let Some(id) = ids.iter().find(|id| !self.is_detaching(**id)) {
+                if predicate(item) {
+                    found.push(*id);
+                }
+            }
+        });
+
+        found
+    }
+
+    /// Calls `f` with every listener which has
+    /// not been detached under all of its ids,
+    /// along with one of the ids it is still
+    /// attached under, then applies the changes
+    /// made during the call once the outermost
+    /// dispatch finishes.
+    pub(crate) fn dispatch(
+        &self,
+        f: &mut dyn FnMut(usize, &S::Item),
+    )
+    {
+        self.detach_unsubscribed();
+
+        let _guard = DispatchGuard::new(self);
+
+        self.storage.borrow().for_each(&mut |ids, item| {
+            self.detach_unsubscribed();
+
+            if let Some(id) = ids.iter().find(|id| !self.is_detaching(**id)) {
+                f(*id, item);
+            }
+        });
+    }
+
+    /// Attaches the listener until the returned
+    /// [`Subscription`] is dropped.
+    pub(crate) fn subscribe(
+        &self,
+        item: S::Item,
+    ) -> Subscription
+    {
+        let id = self.attach(item);
+        let unsubscribed =
+            Arc::downgrade(self.unsubscribed.get_or_init(Arc::default));
+
+        Subscription::new(move || {
+            if let Some(unsubscribed) = unsubscribed.upgrade() {
+                unsubscribed
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(id);
+            }
+        })
+    }
+
+    /// Detaches the listeners whose
+    /// [`Subscription`]s have been dropped since
+    /// this was last called.
+    fn detach_unsubscribed(&self)
+    {
+        let Some(unsubscribed) = self.unsubscribed.get() else {
+            return;
+        };
+        let ids = std::mem::take(
+            &mut *unsubscribed.lock().unwrap_or_else(PoisonError::into_inner),
+        );
+
+        for id in ids {
+            self.detach(id);
+        }
+    }
+
+    fn is_detaching(
+        &self,
+        id: usize,
+    ) -> bool
+    {
+        self.pending
+            .borrow()
+            .iter()
+            .any(|p| matches!(p, Pending::Detach(i) if *i == id))
+    }
+
+    fn apply_pending(&self)
+    {
+        let pending = self.pending.take();
+        let mut storage = self.storage.borrow_mut();
+
+        for change in pending {
+            match change {
+                | Pending::Attach(id, item) => storage.insert(id, item),
+                | Pending::Detach(id) => storage.remove(id),
+            }
+        }
+    }
+}
+
+/// Tracks dispatch depth, applying deferred
+/// changes when the outermost dispatch ends, even
+/// if a listener panicked.
+struct DispatchGuard<'a, S>
+where
+    S: Storage,
+{
+    registry: &'a Registry<S>,
+}
+
+impl<'a, S> DispatchGuard<'a, S>
+where
+    S: Storage,
+{
+    fn new(registry: &'a Registry<S>) -> DispatchGuard<'a, S>
+    {
+        registry.dispatching.set(registry.dispatching.get() + 1);
+
+        DispatchGuard { registry }
+    }
+}
+
+impl<S> Drop for DispatchGuard<'_, S>
+where
+    S: Storage,
+{
+    fn drop(&mut self)
+    {
+        let depth = self.registry.dispatching.get() - 1;
+        self.registry.dispatching.set(depth);
+
+        if depth == 0 {
+            self.registry.apply_pending();
+        }
+    }
+}
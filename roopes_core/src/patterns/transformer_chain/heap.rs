@@ -103,7 +103,6 @@ impl<I, O, P> Heap<I, O, P>
 
 impl<I, O, P> Transformer<I, O> for Heap<I, O, P>
 {
-    #[must_use]
     fn transform(
         &self,
         input: &I,
@@ -12,3 +12,5 @@
 //!
 //! ![transformer chain diagram][transformer-chain-diagram]
 pub mod heap;
+pub mod sync_heap;
+pub mod try_heap;
@@ -0,0 +1,105 @@
+//! Provides a thread-safe, heap-based [`Head`]
+//! which redirects [`Transformer::transform`]
+//! calls to a delegate [`Transformer`], then
+//! enables additional stages to be added
+//! arbitrarily.  Every stage must be [`Send`] and
+//! [`Sync`], so the resulting chain may be
+//! shared between threads.
+
+use crate::primitives::transformer::Transformer;
+use delegate::delegate;
+
+/// The first stage in the thread-safe
+/// [`Transformer`] Chain.  Unique because it does
+/// not use the prior [`Transformer`], but is
+/// instead given input from the client.
+pub struct Head<I, O>
+{
+    transformer: Box<dyn Transformer<I, O> + Send + Sync>,
+}
+
+impl<I, O> Head<I, O>
+{
+    /// Creates a new [`Head`] with a given
+    /// [`Box`]ed [`Transformer`].
+    #[must_use]
+    pub fn new(
+        transformer: Box<dyn Transformer<I, O> + Send + Sync>
+    ) -> Head<I, O>
+    {
+        Head { transformer }
+    }
+
+    /// Adds a stage of execution, giving a new
+    /// [`Heap`] back.
+    pub fn push<N, T>(
+        self,
+        transformer: T,
+    ) -> Heap<I, N, O>
+    where
+        T: Transformer<O, N> + Send + Sync + 'static,
+        N: 'static,
+        O: 'static,
+        I: 'static,
+    {
+        let prev = Box::new(self);
+        let transformer = Box::new(transformer);
+
+        Heap { prev, transformer }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<I, O> Transformer<I, O> for Head<I, O>
+{
+    delegate! {
+            to self.transformer {
+            fn transform(
+                &self,
+                input: &I,
+            ) -> O;
+        }
+    }
+}
+
+/// Stores previous transformations, and the
+/// ability to produce new transformations.
+pub struct Heap<I, O, P>
+{
+    prev: Box<dyn Transformer<I, P> + Send + Sync>,
+    transformer: Box<dyn Transformer<P, O> + Send + Sync>,
+}
+
+impl<I, O, P> Heap<I, O, P>
+{
+    /// Adds a stage of execution, giving a new
+    /// [`Heap`] back.
+    #[must_use]
+    pub fn push<N, E>(
+        self,
+        transformer: E,
+    ) -> Heap<I, N, O>
+    where
+        E: Transformer<O, N> + Send + Sync + 'static,
+        O: 'static,
+        N: 'static,
+        I: 'static,
+        P: 'static,
+    {
+        let prev = Box::new(self);
+        let transformer = Box::new(transformer);
+
+        Heap { prev, transformer }
+    }
+}
+
+impl<I, O, P> Transformer<I, O> for Heap<I, O, P>
+{
+    fn transform(
+        &self,
+        input: &I,
+    ) -> O
+    {
+        self.transformer.transform(&self.prev.transform(input))
+    }
+}
@@ -0,0 +1,164 @@
+//! Provides a heap-based [`Head`] which redirects
+//! [`TryTransformer::try_transform`] calls to a
+//! delegate [`TryTransformer`], then enables
+//! additional fallible stages to be added
+//! arbitrarily.  The chain short-circuits at the
+//! first stage which fails, converting each
+//! stage's error into the chain's error with
+//! [`From`], as `?` does.
+
+use crate::primitives::transformer::TryTransformer;
+use delegate::delegate;
+use std::marker::PhantomData;
+
+/// The first stage in the [`TryTransformer`]
+/// Chain. Its error type becomes the error type
+/// of the whole chain.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// use std::num::ParseIntError;
+///
+/// let chain = transformer_chain::try_heap::Head::new(Box::new(
+///     transformer::TryLambda::new(|s: &String| s.parse::<i32>()),
+/// ))
+/// .push(transformer::TryLambda::new(|i: &i32| {
+///     Ok::<_, ParseIntError>(i * 2)
+/// }));
+///
+/// assert_eq!(Ok(20), chain.try_transform(&"10".to_string()));
+/// assert!(chain.try_transform(&"ten".to_string()).is_err());
+/// ```
+pub struct Head<I, O, E>
+{
+    transformer: Box<dyn TryTransformer<I, O, Error = E>>,
+}
+
+impl<I, O, E> Head<I, O, E>
+{
+    /// Creates a new [`Head`] with a given
+    /// [`Box`]ed [`TryTransformer`].
+    #[must_use]
+    pub fn new(
+        transformer: Box<dyn TryTransformer<I, O, Error = E>>
+    ) -> Head<I, O, E>
+    {
+        Head { transformer }
+    }
+
+    /// Adds a stage of execution, giving a new
+    /// [`Heap`] back.
+    pub fn push<N, T>(
+        self,
+        transformer: T,
+    ) -> Heap<I, N, O, E>
+    where
+        T: TryTransformer<O, N> + 'static,
+        E: From<T::Error> + 'static,
+        N: 'static,
+        O: 'static,
+        I: 'static,
+    {
+        let prev = Box::new(self);
+        let transformer = Box::new(MapError::new(transformer));
+
+        Heap { prev, transformer }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<I, O, E> TryTransformer<I, O> for Head<I, O, E>
+{
+    type Error = E;
+
+    delegate! {
+            to self.transformer {
+            fn try_transform(
+                &self,
+                input: &I,
+            ) -> Result<O, E>;
+        }
+    }
+}
+
+/// Stores previous fallible transformations, and
+/// the ability to produce new ones.
+pub struct Heap<I, O, P, E>
+{
+    prev: Box<dyn TryTransformer<I, P, Error = E>>,
+    transformer: Box<dyn TryTransformer<P, O, Error = E>>,
+}
+
+impl<I, O, P, E> Heap<I, O, P, E>
+{
+    /// Adds a stage of execution, giving a new
+    /// [`Heap`] back.
+    #[must_use]
+    pub fn push<N, T>(
+        self,
+        transformer: T,
+    ) -> Heap<I, N, O, E>
+    where
+        T: TryTransformer<O, N> + 'static,
+        E: From<T::Error> + 'static,
+        O: 'static,
+        N: 'static,
+        I: 'static,
+        P: 'static,
+    {
+        let prev = Box::new(self);
+        let transformer = Box::new(MapError::new(transformer));
+
+        Heap { prev, transformer }
+    }
+}
+
+impl<I, O, P, E> TryTransformer<I, O> for Heap<I, O, P, E>
+{
+    type Error = E;
+
+    fn try_transform(
+        &self,
+        input: &I,
+    ) -> Result<O, E>
+    {
+        self.transformer
+            .try_transform(&self.prev.try_transform(input)?)
+    }
+}
+
+/// Converts the error of a stage into the error
+/// of the chain.
+struct MapError<T, E>
+{
+    transformer: T,
+    _e: PhantomData<fn() -> E>,
+}
+
+impl<T, E> MapError<T, E>
+{
+    fn new(transformer: T) -> MapError<T, E>
+    {
+        MapError {
+            transformer,
+            _e: PhantomData,
+        }
+    }
+}
+
+impl<T, I, O, E> TryTransformer<I, O> for MapError<T, E>
+where
+    T: TryTransformer<I, O>,
+    E: From<T::Error>,
+{
+    type Error = E;
+
+    fn try_transform(
+        &self,
+        input: &I,
+    ) -> Result<O, E>
+    {
+        Ok(self.transformer.try_transform(input)?)
+    }
+}
@@ -0,0 +1,77 @@
+//! A minimal, single-threaded executor, used to
+//! drive futures, e.g.: in examples and tests,
+//! without depending on an external runtime.
+
+use std::{
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{
+        Context,
+        Poll,
+        Wake,
+    },
+    thread::{
+        self,
+        Thread,
+    },
+};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker
+{
+    fn wake(self: Arc<Self>)
+    {
+        self.0.unpark();
+    }
+}
+
+/// Polls the given [`Future`] on the current
+/// thread until it completes, parking between
+/// polls.
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            | Poll::Ready(output) => return output,
+            | Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// A [`Future`] which returns [`Poll::Pending`]
+/// once before completing, to ensure callers
+/// actually yield to the executor.
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct YieldOnce
+{
+    yielded: bool,
+}
+
+#[cfg(test)]
+impl Future for YieldOnce
+{
+    type Output = ();
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<()>
+    {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
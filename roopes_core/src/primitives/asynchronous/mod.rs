@@ -0,0 +1,20 @@
+//! Provides types shared by the asynchronous
+//! counterparts of the primitives and patterns,
+//! e.g.: [`crate::primitives::handler::AsyncHandler`]
+//! or [`crate::patterns::command::AsyncCommand`].
+//! No particular runtime is assumed: the returned
+//! futures may be driven by any executor, or by
+//! [`executor::block_on`] where none is at hand.
+
+use std::{
+    future::Future,
+    pin::Pin,
+};
+
+pub mod executor;
+
+/// A heap-allocated, type-erased [`Future`]
+/// borrowing for `'a`.  Returned by the
+/// asynchronous traits so they remain usable
+/// behind `dyn`.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
@@ -12,9 +12,11 @@
 
 pub mod iterator;
 pub mod lambda;
+pub mod sync_iterator;
 
 pub use iterator::Iterator;
 pub use lambda::Lambda;
+pub use sync_iterator::SyncIterator;
 
 /// The [`Emitter`] [`Delegate`] must be capable
 /// of returning potentially unlimited owned
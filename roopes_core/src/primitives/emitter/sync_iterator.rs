@@ -0,0 +1,40 @@
+//! Provides a thread-safe wrapper struct around
+//! [`iter::Iterator`].
+use super::Emitter;
+use std::{
+    iter,
+    sync::Mutex,
+};
+
+/// Wraps an [`iter::Iterator`] in an
+/// [`Emitter<Option>`].  Unlike
+/// [`super::Iterator`], the wrapped iterator is
+/// guarded by a [`Mutex`], so [`SyncIterator`]
+/// is [`Send`] and [`Sync`].
+pub struct SyncIterator<R>
+{
+    iter: Mutex<Box<dyn iter::Iterator<Item = R> + Send>>,
+}
+
+impl<R> SyncIterator<R>
+{
+    /// Creates a new [`SyncIterator`] with a
+    /// given [`Box`]ed [`iter::Iterator`].
+    pub fn new(
+        iter: Mutex<Box<dyn iter::Iterator<Item = R> + Send>>
+    ) -> SyncIterator<R>
+    {
+        SyncIterator { iter }
+    }
+}
+
+impl<R> Emitter<Option<R>> for SyncIterator<R>
+{
+    fn emit(&self) -> Option<R>
+    {
+        self.iter
+            .lock()
+            .expect("SyncIterator mutex was poisoned.")
+            .next()
+    }
+}
@@ -32,9 +32,32 @@ fn iterator_emitter()
         emitter::Iterator::new(rcb_iter)
     };
 
-    assert!(test_iter.emit() == Some(1));
-    assert!(test_iter.emit() == Some(2));
-    assert!(test_iter.emit() == Some(3));
-    assert!(test_iter.emit() == None);
-    assert!(test_iter.emit() == None);
+    assert_eq!(test_iter.emit(), Some(1));
+    assert_eq!(test_iter.emit(), Some(2));
+    assert_eq!(test_iter.emit(), Some(3));
+    assert!(test_iter.emit().is_none());
+    assert!(test_iter.emit().is_none());
+}
+
+#[test]
+fn sync_iterator_emitter()
+{
+    use std::sync::{
+        Arc,
+        Mutex,
+    };
+
+    let test_iter = Arc::new(emitter::SyncIterator::new(Mutex::new(Box::new(
+        vec![1, 2, 3].into_iter(),
+    ))));
+
+    let test_iter_ext = test_iter.clone();
+    let first = std::thread::spawn(move || test_iter_ext.emit())
+        .join()
+        .unwrap();
+
+    assert_eq!(first, Some(1));
+    assert_eq!(test_iter.emit(), Some(2));
+    assert_eq!(test_iter.emit(), Some(3));
+    assert!(test_iter.emit().is_none());
 }
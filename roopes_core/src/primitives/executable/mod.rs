@@ -12,9 +12,11 @@
 
 pub mod heap;
 pub mod lambda;
+pub mod sync_heap;
 
 pub use heap::Heap;
 pub use lambda::Lambda;
+pub use sync_heap::SyncHeap;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,48 @@
+//! Provides a thread-safe, heap-based
+//! [`Executable`] which redirects
+//! [`Executable::execute`] calls to a delegate
+//! [`Executable`].  Useful when [`Executable`]s
+//! need to be shared between threads.
+
+use super::Executable;
+use std::sync::Arc;
+
+/// Stores an indirected [`Executable`] in an
+/// [`Arc`] for later delegation.  Unlike
+/// [`super::Heap`], [`SyncHeap`] is [`Send`] and
+/// [`Sync`], and cloning it shares the delegate.
+#[derive(Clone)]
+pub struct SyncHeap
+{
+    delegate: Arc<dyn Executable + Send + Sync>,
+}
+
+impl SyncHeap
+{
+    /// Creates a new [`SyncHeap`] with a given
+    /// [`Arc`]ed [`Executable`]. # Examples
+    /// ``` rust
+    /// use roopes::prelude::*;
+    /// use std::sync::Arc;
+    /// let my_executable =
+    ///     executable::SyncHeap::new(Arc::new(executable::Lambda::new(|| {
+    ///         println!("Hello World.");
+    ///     })));
+    /// std::thread::spawn(move || my_executable.execute())
+    ///     .join()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn new(delegate: Arc<dyn Executable + Send + Sync>) -> Self
+    {
+        Self { delegate }
+    }
+}
+
+impl Executable for SyncHeap
+{
+    fn execute(&self)
+    {
+        self.delegate.execute();
+    }
+}
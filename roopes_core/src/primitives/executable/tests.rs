@@ -25,3 +25,30 @@ fn simple_heap_command()
 
     assert!((*has_run.borrow()));
 }
+
+#[test]
+fn sync_heap_execute_across_threads()
+{
+    use std::sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    };
+
+    let has_run = Arc::new(AtomicBool::new(false));
+    let has_run_ext = has_run.clone();
+
+    let executable = executable::SyncHeap::new(Arc::new(
+        executable::Lambda::new(move || {
+            has_run_ext.store(true, Ordering::SeqCst);
+        }),
+    ));
+
+    std::thread::spawn(move || executable.execute())
+        .join()
+        .unwrap();
+
+    assert!(has_run.load(Ordering::SeqCst));
+}
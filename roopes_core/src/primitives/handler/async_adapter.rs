@@ -0,0 +1,84 @@
+//! Provides an [`AsyncHandler`] which wraps a
+//! synchronous [`Handler`], so existing
+//! [`Handler`]s can be used wherever an
+//! [`AsyncHandler`] is expected.
+
+use super::{
+    AsyncHandler,
+    Handler,
+};
+use crate::primitives::asynchronous::BoxFuture;
+use std::marker::PhantomData;
+
+/// Adapts a [`Handler`] into an
+/// [`AsyncHandler`].  The delegate is called when
+/// the returned future is first polled, and the
+/// future completes immediately afterwards.
+///
+/// # Examples
+/// ``` rust
+/// use roopes::{
+///     prelude::*,
+///     primitives::asynchronous::executor::block_on,
+/// };
+/// use std::cell::RefCell;
+///
+/// let received = RefCell::new(Vec::new());
+/// let handler: handler::AsyncAdapter<_, String> =
+///     handler::Lambda::new(|message: &String| {
+///         received.borrow_mut().push(message.clone());
+///     })
+///     .into();
+///
+/// let message = "Hello world!".to_string();
+/// let future = handler.handle_async(&message);
+/// assert!(received.borrow().is_empty());
+///
+/// block_on(future);
+/// assert_eq!(vec![message], *received.borrow());
+/// ```
+pub struct AsyncAdapter<H, M>
+where
+    H: Handler<M>,
+{
+    delegate: H,
+    _t: PhantomData<M>,
+}
+
+impl<H, M> AsyncAdapter<H, M>
+where
+    H: Handler<M>,
+{
+    /// Creates an [`AsyncAdapter`] from a given
+    /// [`Handler`].
+    pub fn new(delegate: H) -> AsyncAdapter<H, M>
+    {
+        AsyncAdapter {
+            delegate,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<H, M> AsyncHandler<M> for AsyncAdapter<H, M>
+where
+    H: Handler<M>,
+{
+    fn handle_async<'a>(
+        &'a self,
+        message: &'a M,
+    ) -> BoxFuture<'a, ()>
+    {
+        Box::pin(async move { self.delegate.handle(message) })
+    }
+}
+
+impl<H, M> From<H> for AsyncAdapter<H, M>
+where
+    H: Handler<M>,
+{
+    fn from(delegate: H) -> Self
+    {
+        AsyncAdapter::new(delegate)
+    }
+}
@@ -0,0 +1,45 @@
+//! Provides a heap-based [`AsyncHandler`] which
+//! redirects [`AsyncHandler::handle_async`] calls to a
+//! delegate [`AsyncHandler`].  Useful when
+//! redirecting calls to unknown or mixed lists of
+//! [`AsyncHandler`]s.
+
+use super::AsyncHandler;
+use crate::primitives::asynchronous::BoxFuture;
+use delegate::delegate;
+
+/// Stores an indirected [`AsyncHandler`] in a
+/// [`Box`] for later delegation.
+pub struct AsyncHeap<M>
+{
+    delegate: Box<dyn AsyncHandler<M>>,
+}
+
+impl<M> AsyncHeap<M>
+{
+    /// Creates a new [`AsyncHeap`] with a given
+    /// [`Box`]ed [`AsyncHandler`].
+    #[must_use]
+    pub fn new(delegate: Box<dyn AsyncHandler<M>>) -> AsyncHeap<M>
+    {
+        AsyncHeap { delegate }
+    }
+}
+
+impl<M> From<Box<dyn AsyncHandler<M>>> for AsyncHeap<M>
+{
+    fn from(value: Box<dyn AsyncHandler<M>>) -> Self
+    {
+        AsyncHeap::new(value)
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<M> AsyncHandler<M> for AsyncHeap<M>
+{
+    delegate! {
+        to self.delegate {
+           fn handle_async<'a>(&'a self, message: &'a M) -> BoxFuture<'a, ()>;
+        }
+    }
+}
@@ -10,13 +10,24 @@
 //!
 //! ![handler diagram][handler-diagram]
 
+pub mod async_adapter;
+pub mod async_heap;
 pub mod hash;
 pub mod heap;
 pub mod lambda;
+pub mod sync_heap;
+pub mod try_heap;
+pub mod try_lambda;
 
+use crate::primitives::asynchronous::BoxFuture;
+pub use async_adapter::AsyncAdapter;
+pub use async_heap::AsyncHeap;
 pub use hash::Hashable;
 pub use heap::Heap;
 pub use lambda::Lambda;
+pub use sync_heap::SyncHeap;
+pub use try_heap::TryHeap;
+pub use try_lambda::TryLambda;
 
 #[cfg(test)]
 mod tests;
@@ -32,6 +43,39 @@ pub trait Handler<M>
     );
 }
 
+/// The fallible counterpart of [`Handler`].
+/// Handles messages via borrowing, reporting
+/// whether handling failed.
+pub trait TryHandler<M>
+{
+    /// The error produced when handling fails.
+    type Error;
+
+    /// Receives a borrowed value.
+    ///
+    /// # Errors
+    /// [`TryHandler::Error`]: The message could
+    /// not be handled.
+    fn try_handle(
+        &self,
+        message: &M,
+    ) -> Result<(), Self::Error>;
+}
+
+/// The asynchronous counterpart of [`Handler`].
+/// Handles messages via borrowing, completing at
+/// some later point.
+pub trait AsyncHandler<M>
+{
+    /// Receives a borrowed value, giving back a
+    /// future which completes once the value has
+    /// been handled.
+    fn handle_async<'a>(
+        &'a self,
+        message: &'a M,
+    ) -> BoxFuture<'a, ()>;
+}
+
 // impl<C, M> From<Lambda<C, M>> for Heap<M>
 // where
 //     C: Delegate<M> + 'static,
@@ -43,9 +87,13 @@ pub trait Handler<M>
 //     }
 // }
 
-/// Exposes the [`Handler`] type at the library
-/// level.
+/// Exposes the [`Handler`], [`TryHandler`] and
+/// [`AsyncHandler`] types at the library level.
 pub mod prelude
 {
-    pub use super::Handler;
+    pub use super::{
+        AsyncHandler,
+        Handler,
+        TryHandler,
+    };
 }
@@ -0,0 +1,68 @@
+//! Provides a thread-safe, heap-based
+//! [`Handler`] which redirects
+//! [`Handler::handle`] calls to a delegate
+//! [`Handler`].  Useful when [`Handler`]s need to
+//! be shared between threads.
+
+use super::Handler;
+use delegate::delegate;
+use std::sync::Arc;
+
+/// Stores an indirected [`Handler`] in an [`Arc`]
+/// for later delegation.  Unlike
+/// [`super::Heap`], [`SyncHeap`] is [`Send`] and
+/// [`Sync`], and cloning it shares the delegate.
+pub struct SyncHeap<M>
+{
+    delegate: Arc<dyn Handler<M> + Send + Sync>,
+}
+
+impl<M> SyncHeap<M>
+{
+    /// Creates a new [`SyncHeap`] with a given
+    /// [`Arc`]ed [`Handler`]. # Examples
+    /// ``` rust
+    /// use roopes::prelude::*;
+    /// use std::sync::Arc;
+    /// let my_handler =
+    ///     handler::SyncHeap::new(Arc::new(handler::Lambda::new(|message| {
+    ///         println!("{message}");
+    ///     })));
+    /// std::thread::spawn(move || {
+    ///     my_handler.handle(&"Hello World.".to_string());
+    /// })
+    /// .join()
+    /// .unwrap();
+    /// ```
+    #[must_use]
+    pub fn new(delegate: Arc<dyn Handler<M> + Send + Sync>) -> SyncHeap<M>
+    {
+        SyncHeap { delegate }
+    }
+}
+
+impl<M> Clone for SyncHeap<M>
+{
+    fn clone(&self) -> Self
+    {
+        SyncHeap::new(self.delegate.clone())
+    }
+}
+
+impl<M> From<Arc<dyn Handler<M> + Send + Sync>> for SyncHeap<M>
+{
+    fn from(value: Arc<dyn Handler<M> + Send + Sync>) -> Self
+    {
+        SyncHeap::new(value)
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<M> Handler<M> for SyncHeap<M>
+{
+    delegate! {
+        to self.delegate {
+           fn handle(&self, message: &M);
+        }
+    }
+}
@@ -36,3 +36,138 @@ fn simple_heap_refcell_mutation()
     hh.handle(&true);
     assert!((*has_run.borrow()));
 }
+
+#[test]
+fn sync_heap_handle_across_threads()
+{
+    use std::sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    };
+
+    let total = Arc::new(AtomicUsize::new(0));
+    let total_ext = total.clone();
+
+    let sh = handler::SyncHeap::new(Arc::new(handler::Lambda::new(
+        move |v: &usize| {
+            total_ext.fetch_add(*v, Ordering::SeqCst);
+        },
+    )));
+
+    let workers: Vec<_> = (1..=4)
+        .map(|i| {
+            let sh = sh.clone();
+            std::thread::spawn(move || sh.handle(&i))
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(10, total.load(Ordering::SeqCst));
+}
+
+#[test]
+fn async_adapter_handle()
+{
+    use crate::primitives::asynchronous::executor::block_on;
+
+    let has_run = Rc::new(RefCell::new(false));
+    let has_run_ext = has_run.clone();
+
+    let adapter: handler::AsyncAdapter<_, bool> =
+        handler::Lambda::new(move |v: &bool| {
+            (*has_run_ext.borrow_mut()) = *v;
+        })
+        .into();
+
+    let future = adapter.handle_async(&true);
+
+    assert!(!(*has_run.borrow()));
+    block_on(future);
+    assert!((*has_run.borrow()));
+}
+
+#[test]
+fn async_heap_handle()
+{
+    use crate::primitives::asynchronous::{
+        executor::{
+            block_on,
+            YieldOnce,
+        },
+        BoxFuture,
+    };
+
+    struct YieldingHandler
+    {
+        total: Rc<RefCell<usize>>,
+    }
+    impl AsyncHandler<usize> for YieldingHandler
+    {
+        fn handle_async<'a>(
+            &'a self,
+            message: &'a usize,
+        ) -> BoxFuture<'a, ()>
+        {
+            Box::pin(async move {
+                YieldOnce::default().await;
+                (*self.total.borrow_mut()) += *message;
+            })
+        }
+    }
+
+    let total = Rc::new(RefCell::new(0));
+
+    let heap = handler::AsyncHeap::new(Box::new(YieldingHandler {
+        total: total.clone(),
+    }));
+
+    block_on(async {
+        heap.handle_async(&2).await;
+        heap.handle_async(&3).await;
+    });
+
+    assert_eq!(5, *total.borrow());
+}
+
+#[test]
+fn try_lambda_reports_failure()
+{
+    let handler = handler::TryLambda::new(|i: &i32| {
+        if *i >= 0 {
+            Ok(())
+        } else {
+            Err(format!("{i} is negative"))
+        }
+    });
+
+    assert_eq!(Ok(()), handler.try_handle(&1));
+    assert_eq!(Err("-1 is negative".to_string()), handler.try_handle(&-1));
+}
+
+#[test]
+fn try_heap_delegates()
+{
+    let calls = Rc::new(RefCell::new(0));
+    let calls_ext = calls.clone();
+
+    let heap = handler::TryHeap::new(Box::new(handler::TryLambda::new(
+        move |i: &i32| {
+            (*calls_ext.borrow_mut()) += 1;
+            if *i == 0 {
+                Err(())
+            } else {
+                Ok(())
+            }
+        },
+    )));
+
+    assert_eq!(Ok(()), heap.try_handle(&1));
+    assert_eq!(Err(()), heap.try_handle(&0));
+    assert_eq!(2, *calls.borrow());
+}
@@ -0,0 +1,46 @@
+//! Provides a heap-based [`TryHandler`] which
+//! redirects [`TryHandler::try_handle`] calls to
+//! a delegate [`TryHandler`].  Useful when
+//! redirecting calls to unknown or mixed lists of
+//! [`TryHandler`]s sharing an error type.
+
+use super::TryHandler;
+use delegate::delegate;
+
+/// Stores an indirected [`TryHandler`] in a
+/// [`Box`] for later delegation.
+pub struct TryHeap<M, E>
+{
+    delegate: Box<dyn TryHandler<M, Error = E>>,
+}
+
+impl<M, E> TryHeap<M, E>
+{
+    /// Creates a new [`TryHeap`] with a given
+    /// [`Box`]ed [`TryHandler`].
+    #[must_use]
+    pub fn new(delegate: Box<dyn TryHandler<M, Error = E>>) -> TryHeap<M, E>
+    {
+        TryHeap { delegate }
+    }
+}
+
+impl<M, E> From<Box<dyn TryHandler<M, Error = E>>> for TryHeap<M, E>
+{
+    fn from(value: Box<dyn TryHandler<M, Error = E>>) -> Self
+    {
+        TryHeap::new(value)
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<M, E> TryHandler<M> for TryHeap<M, E>
+{
+    type Error = E;
+
+    delegate! {
+        to self.delegate {
+           fn try_handle(&self, message: &M) -> Result<(), E>;
+        }
+    }
+}
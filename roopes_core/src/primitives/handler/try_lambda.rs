@@ -0,0 +1,76 @@
+//! Provides a simple wrapper [`TryLambda`] struct
+//! around [`Delegate`], `Fn(&M) -> Result<(), E>`
+//! types.
+
+use super::TryHandler;
+use std::marker::PhantomData;
+
+/// A [`TryLambda`] [`Delegate`] receives a
+/// borrowed message and reports failure.
+pub trait Delegate<M, E> = Fn(&M) -> Result<(), E>;
+
+/// Defines an encapsulated [`TryHandler`] as a
+/// struct, which just delegates its execution to
+/// the attached [`Delegate`].
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// let handler = handler::TryLambda::new(|msg: &i32| {
+///     if *msg < 0 {
+///         Err("negative")
+///     } else {
+///         Ok(())
+///     }
+/// });
+/// assert!(handler.try_handle(&1).is_ok());
+/// assert_eq!(Err("negative"), handler.try_handle(&-1));
+/// ```
+#[derive(Clone)]
+pub struct TryLambda<C, M, E>
+where
+    C: Delegate<M, E>,
+{
+    delegate: C,
+    _t: PhantomData<(M, E)>,
+}
+
+impl<C, M, E> TryLambda<C, M, E>
+where
+    C: Delegate<M, E>,
+{
+    /// Creates a [`TryLambda`] from a given
+    /// [`Delegate`].
+    pub fn new(delegate: C) -> TryLambda<C, M, E>
+    {
+        TryLambda {
+            delegate,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<C, M, E> TryHandler<M> for TryLambda<C, M, E>
+where
+    C: Delegate<M, E>,
+{
+    type Error = E;
+
+    fn try_handle(
+        &self,
+        message: &M,
+    ) -> Result<(), E>
+    {
+        (self.delegate)(message)
+    }
+}
+
+impl<C, M, E> From<C> for TryLambda<C, M, E>
+where
+    C: Delegate<M, E>,
+{
+    fn from(delegate: C) -> Self
+    {
+        TryLambda::new(delegate)
+    }
+}
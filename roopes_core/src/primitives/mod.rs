@@ -1,17 +1,20 @@
 //! This module supplies essential types used
 //! elsewhere in the library.
 
+pub mod asynchronous;
 pub mod emitter;
 pub mod executable;
 pub mod handler;
 pub mod transformer;
 
-/// Exposes [`emitter`], [`executable`],
-/// [`handler`], and [`transformer`] submodules
-/// and their preludes at the library level.
+/// Exposes [`asynchronous`], [`emitter`],
+/// [`executable`], [`handler`], and
+/// [`transformer`] submodules and their preludes
+/// at the library level.
 pub mod prelude
 {
     pub use super::{
+        asynchronous,
         emitter,
         executable,
         handler,
@@ -0,0 +1,59 @@
+//! Provides an [`AsyncTransformer`] which wraps a
+//! synchronous [`Transformer`].
+
+use super::{
+    AsyncTransformer,
+    Transformer,
+};
+use crate::primitives::asynchronous::BoxFuture;
+use std::marker::PhantomData;
+
+/// Adapts a [`Transformer`] into an
+/// [`AsyncTransformer`].  The delegate is called
+/// when the returned future is first polled, and
+/// the future resolves immediately afterwards.
+pub struct AsyncAdapter<T, I, O>
+where
+    T: Transformer<I, O>,
+{
+    delegate: T,
+    _t: PhantomData<(I, O)>,
+}
+
+impl<T, I, O> AsyncAdapter<T, I, O>
+where
+    T: Transformer<I, O>,
+{
+    /// Creates an [`AsyncAdapter`] from a given
+    /// [`Transformer`].
+    pub fn new(delegate: T) -> AsyncAdapter<T, I, O>
+    {
+        AsyncAdapter {
+            delegate,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T, I, O> AsyncTransformer<I, O> for AsyncAdapter<T, I, O>
+where
+    T: Transformer<I, O>,
+{
+    fn transform_async<'a>(
+        &'a self,
+        input: &'a I,
+    ) -> BoxFuture<'a, O>
+    {
+        Box::pin(async move { self.delegate.transform(input) })
+    }
+}
+
+impl<T, I, O> From<T> for AsyncAdapter<T, I, O>
+where
+    T: Transformer<I, O>,
+{
+    fn from(delegate: T) -> Self
+    {
+        AsyncAdapter::new(delegate)
+    }
+}
@@ -0,0 +1,36 @@
+//! This module contains types which store an
+//! [`AsyncTransformer`] on the heap.  This is
+//! particularly useful to allow for
+//! non-uniform-sized [`AsyncTransformer`] types.
+
+use super::AsyncTransformer;
+use crate::primitives::asynchronous::BoxFuture;
+use delegate::delegate;
+
+/// Stores an indirected [`AsyncTransformer`] in a
+/// [`Box`] for later use.
+pub struct AsyncHeap<I, O>
+{
+    delegate: Box<dyn AsyncTransformer<I, O>>,
+}
+
+impl<I, O> AsyncHeap<I, O>
+{
+    /// Creates a new [`AsyncHeap`] which contains
+    /// a [`Box`]ed [`AsyncTransformer`].
+    #[must_use]
+    pub fn new(delegate: Box<dyn AsyncTransformer<I, O>>) -> AsyncHeap<I, O>
+    {
+        AsyncHeap { delegate }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<I, O> AsyncTransformer<I, O> for AsyncHeap<I, O>
+{
+    delegate! {
+        to self.delegate {
+           fn transform_async<'a>(&'a self, input: &'a I) -> BoxFuture<'a, O>;
+        }
+    }
+}
@@ -11,11 +11,22 @@
 //!
 //! ![transformer diagram][transformer-diagram]
 
+pub mod async_adapter;
+pub mod async_heap;
 pub mod heap;
 pub mod lambda;
+pub mod sync_heap;
+pub mod try_heap;
+pub mod try_lambda;
 
+use crate::primitives::asynchronous::BoxFuture;
+pub use async_adapter::AsyncAdapter;
+pub use async_heap::AsyncHeap;
 pub use heap::Heap;
 pub use lambda::Lambda;
+pub use sync_heap::SyncHeap;
+pub use try_heap::TryHeap;
+pub use try_lambda::TryLambda;
 
 /// A [`Transformer`] receives a borrowed value
 /// and creates a new value of a
@@ -32,11 +43,54 @@ pub trait Transformer<I, O>
     ) -> O;
 }
 
-/// Exposes the [`Transformer`] type at the
-/// library level.
+/// The fallible counterpart of [`Transformer`].
+/// Receives a borrowed value and either creates a
+/// new value of a possibly different type, or
+/// reports why it could not.
+pub trait TryTransformer<I, O>
+{
+    /// The error produced when the transformation
+    /// fails.
+    type Error;
+
+    /// Performs the transformation to produce the
+    /// output, giving ownership of the new
+    /// value to the caller.
+    ///
+    /// # Errors
+    /// [`TryTransformer::Error`]: The input could
+    /// not be transformed.
+    fn try_transform(
+        &self,
+        input: &I,
+    ) -> Result<O, Self::Error>;
+}
+
+/// The asynchronous counterpart of
+/// [`Transformer`].  Receives a borrowed value
+/// and eventually creates a new value of a
+/// possibly different type, giving ownership to
+/// the caller.
+pub trait AsyncTransformer<I, O>
+{
+    /// Performs the transformation, giving back a
+    /// future which resolves to the output.
+    fn transform_async<'a>(
+        &'a self,
+        input: &'a I,
+    ) -> BoxFuture<'a, O>;
+}
+
+/// Exposes the [`Transformer`],
+/// [`TryTransformer`] and [`AsyncTransformer`]
+/// types at the library level.
 pub mod prelude
 {
-    pub use super::Transformer;
+    pub use super::{
+        AsyncTransformer,
+        Transformer,
+        TryTransformer,
+    };
 }
 
 #[cfg(test)]
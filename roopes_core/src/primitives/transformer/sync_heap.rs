@@ -0,0 +1,49 @@
+//! This module contains types which store a
+//! [`Transformer`] on the heap in a thread-safe
+//! manner.  This is particularly useful when
+//! [`Transformer`]s need to be shared between
+//! threads.
+
+use super::Transformer;
+use delegate::delegate;
+use std::sync::Arc;
+
+/// Stores an indirected [`Transformer`] in an
+/// [`Arc`] for later use.  Unlike
+/// [`super::Heap`], [`SyncHeap`] is [`Send`] and
+/// [`Sync`], and cloning it shares the delegate.
+pub struct SyncHeap<I, O>
+{
+    delegate: Arc<dyn Transformer<I, O> + Send + Sync>,
+}
+
+impl<I, O> SyncHeap<I, O>
+{
+    /// Creates a new [`SyncHeap`] which contains
+    /// an [`Arc`]ed [`Transformer`].
+    #[must_use]
+    pub fn new(
+        delegate: Arc<dyn Transformer<I, O> + Send + Sync>
+    ) -> SyncHeap<I, O>
+    {
+        SyncHeap { delegate }
+    }
+}
+
+impl<I, O> Clone for SyncHeap<I, O>
+{
+    fn clone(&self) -> Self
+    {
+        SyncHeap::new(self.delegate.clone())
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<I, O> Transformer<I, O> for SyncHeap<I, O>
+{
+    delegate! {
+        to self.delegate {
+           fn transform(&self, input: &I) -> O;
+        }
+    }
+}
@@ -39,3 +39,98 @@ fn simple_heap_refcell_mutation()
     assert!((*has_run.borrow()));
     assert_eq!(2, hh.transform(&true));
 }
+
+#[test]
+fn sync_heap_transform_across_threads()
+{
+    use std::sync::Arc;
+
+    let sh = transformer::SyncHeap::new(Arc::new(transformer::Lambda::new(
+        |v: &usize| v * 2,
+    )));
+
+    let workers: Vec<_> = (1..=3)
+        .map(|i| {
+            let sh = sh.clone();
+            std::thread::spawn(move || sh.transform(&i))
+        })
+        .collect();
+
+    let results: Vec<_> =
+        workers.into_iter().map(|w| w.join().unwrap()).collect();
+
+    assert_eq!(vec![2, 4, 6], results);
+}
+
+#[test]
+fn async_adapter_and_heap_transform()
+{
+    use crate::primitives::asynchronous::executor::block_on;
+
+    let adapter: transformer::AsyncAdapter<_, usize, String> =
+        transformer::Lambda::new(|v: &usize| format!("{v}!")).into();
+    let heap = transformer::AsyncHeap::new(Box::new(adapter));
+
+    assert_eq!("3!", block_on(heap.transform_async(&3)));
+}
+
+#[test]
+fn try_heap_transforms_or_fails()
+{
+    let heap = transformer::TryHeap::new(Box::new(
+        transformer::TryLambda::new(|s: &String| s.parse::<u8>()),
+    ));
+
+    assert_eq!(Ok(7), heap.try_transform(&"7".to_string()));
+    assert!(heap.try_transform(&"700".to_string()).is_err());
+}
+
+#[test]
+fn try_chain_short_circuits()
+{
+    #[derive(Debug, PartialEq)]
+    enum ChainError
+    {
+        Parse,
+        Range,
+    }
+    impl From<std::num::ParseIntError> for ChainError
+    {
+        fn from(_: std::num::ParseIntError) -> Self
+        {
+            ChainError::Parse
+        }
+    }
+
+    let later_ran = Rc::new(RefCell::new(false));
+    let later_ran_ext = later_ran.clone();
+
+    let chain = transformer_chain::try_heap::Head::new(Box::new(
+        transformer::TryLambda::new(|s: &String| {
+            Ok::<_, ChainError>(s.parse::<i32>()?)
+        }),
+    ))
+    .push(transformer::TryLambda::new(|i: &i32| {
+        if *i < 100 {
+            Ok(i * 10)
+        } else {
+            Err(ChainError::Range)
+        }
+    }))
+    .push(transformer::TryLambda::new(move |i: &i32| {
+        (*later_ran_ext.borrow_mut()) = true;
+        Ok::<_, ChainError>(i + 1)
+    }));
+
+    assert_eq!(
+        Err(ChainError::Parse),
+        chain.try_transform(&"x".to_string())
+    );
+    assert_eq!(
+        Err(ChainError::Range),
+        chain.try_transform(&"100".to_string())
+    );
+    assert!(!*later_ran.borrow());
+    assert_eq!(Ok(51), chain.try_transform(&"5".to_string()));
+    assert!(*later_ran.borrow());
+}
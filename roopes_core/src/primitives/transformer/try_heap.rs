@@ -0,0 +1,39 @@
+//! This module contains types which store a
+//! [`TryTransformer`] on the heap, allowing for
+//! non-uniform-sized [`TryTransformer`] types
+//! which share an error type.
+
+use super::TryTransformer;
+use delegate::delegate;
+
+/// Stores an indirected [`TryTransformer`] in a
+/// [`Box`] for later use.
+pub struct TryHeap<I, O, E>
+{
+    delegate: Box<dyn TryTransformer<I, O, Error = E>>,
+}
+
+impl<I, O, E> TryHeap<I, O, E>
+{
+    /// Creates a new [`TryHeap`] which contains a
+    /// [`Box`]ed [`TryTransformer`].
+    #[must_use]
+    pub fn new(
+        delegate: Box<dyn TryTransformer<I, O, Error = E>>
+    ) -> TryHeap<I, O, E>
+    {
+        TryHeap { delegate }
+    }
+}
+
+#[allow(clippy::inline_always)]
+impl<I, O, E> TryTransformer<I, O> for TryHeap<I, O, E>
+{
+    type Error = E;
+
+    delegate! {
+        to self.delegate {
+           fn try_transform(&self, input: &I) -> Result<O, E>;
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Provides a simple wrapper struct around
+//! [`Delegate`], `Fn(&I) -> Result<O, E>` types.
+
+use super::TryTransformer;
+use std::marker::PhantomData;
+
+/// [`TryLambda`] [`Delegate`]s are given input
+/// and produce owned output, or an error.
+pub trait Delegate<I, O, E> = Fn(&I) -> Result<O, E>;
+
+/// Provides a type which redirects fallible
+/// transformations to its enclosed [`Delegate`].
+///
+/// # Examples
+/// ``` rust
+/// use roopes::prelude::*;
+/// let parse = transformer::TryLambda::new(|s: &String| s.parse::<i32>());
+/// assert_eq!(Ok(10), parse.try_transform(&"10".to_string()));
+/// assert!(parse.try_transform(&"ten".to_string()).is_err());
+/// ```
+#[derive(Clone)]
+pub struct TryLambda<C, I, O, E>
+where
+    C: Delegate<I, O, E>,
+{
+    delegate: C,
+    _t: PhantomData<(I, O, E)>,
+}
+
+impl<C, I, O, E> TryLambda<C, I, O, E>
+where
+    C: Delegate<I, O, E>,
+{
+    /// Creates a [`TryLambda`] from a given
+    /// [`Delegate`].
+    pub fn new(delegate: C) -> TryLambda<C, I, O, E>
+    {
+        TryLambda {
+            delegate,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<C, I, O, E> TryTransformer<I, O> for TryLambda<C, I, O, E>
+where
+    C: Delegate<I, O, E>,
+{
+    type Error = E;
+
+    fn try_transform(
+        &self,
+        input: &I,
+    ) -> Result<O, E>
+    {
+        (self.delegate)(input)
+    }
+}
+
+impl<C, I, O, E> From<C> for TryLambda<C, I, O, E>
+where
+    C: Delegate<I, O, E>,
+{
+    fn from(delegate: C) -> Self
+    {
+        TryLambda::new(delegate)
+    }
+}
@@ -1,15 +1,18 @@
-use super::transformer_params::TransformerParams;
+use super::transformer_params::{
+    FieldParams,
+    TransformerParams,
+};
 use crate::common::VecTokenStringTransformer;
-use proc_macro2::TokenStream;
+use proc_macro2::{
+    Span,
+    TokenStream,
+};
 use quote::{
     format_ident,
     quote,
 };
 use roopes_core::prelude::*;
-use syn::{
-    Field,
-    Ident,
-};
+use syn::Ident;
 
 pub(super) struct FieldIndicatorSection;
 impl Transformer<TransformerParams, TokenStream> for FieldIndicatorSection
@@ -26,7 +29,7 @@ impl Transformer<TransformerParams, TokenStream> for FieldIndicatorSection
 
         let field_indicator_structs = {
             let field_indicator_structs = input
-                .field_idents()
+                .required_idents()
                 .iter()
                 .map(|f| (f, input))
                 .map(|e| FieldIndicatorStruct.transform(&e))
@@ -55,10 +58,8 @@ impl Transformer<(&Ident, &TransformerParams), TokenStream>
     ) -> TokenStream
     {
         let visibility = input.visibility().clone();
-        let unpop =
-            TransformerParams::field_unpopulated_struct_name(field_ident);
-        let popped =
-            TransformerParams::field_populated_struct_name(field_ident);
+        let unpop = input.field_unpopulated_struct_name(field_ident);
+        let popped = input.field_populated_struct_name(field_ident);
         let builder_unpopped = input.builder_unpopped();
         let builder_popped = input.builder_popped();
         let builder_poppable = input.builder_poppable();
@@ -84,13 +85,15 @@ impl Transformer<TransformerParams, TokenStream> for Builder
     ) -> TokenStream
     {
         let visibility = input.visibility().clone();
+        let builder = input.builder();
+        let generic_params = input.generic_params();
+        let field_generic_names = input.field_generic_names();
         let poppable_where_block = input.poppable_where_block();
-        let builder_generic_ty = input.builder_generic_ty();
         let builder_fields = BuilderFieldsDeclarations.transform(input);
 
         quote! {
             #visibility struct
-                #builder_generic_ty
+                #builder<#(#generic_params,)* #(#field_generic_names),*>
                 #poppable_where_block
             {
                 #builder_fields
@@ -107,7 +110,7 @@ impl Transformer<TransformerParams, TokenStream> for BuilderFieldsDeclarations
         input: &TransformerParams,
     ) -> TokenStream
     {
-        let phantom_fields = input.field_idents().into_iter().map(|id| {
+        let phantom_fields = input.required_idents().into_iter().map(|id| {
             let fgn = TransformerParams::field_generic_name(&id);
             let phantom_field = TransformerParams::phantom_field_name(&id);
 
@@ -117,12 +120,11 @@ impl Transformer<TransformerParams, TokenStream> for BuilderFieldsDeclarations
         });
 
         let builder_fields = input.fields().iter().map(|field| {
-            let field = field.clone();
-            let id = field.ident.unwrap();
-            let ty = field.ty;
+            let id = field.ident();
+            let ty = field.storage_ty();
 
             quote! {
-                #id: std::option::Option<#ty>
+                #id: #ty
             }
         });
 
@@ -144,7 +146,9 @@ impl Transformer<TransformerParams, TokenStream> for NewImpl
     {
         let visibility = input.visibility().clone();
         let builder = input.builder();
-        let all_unpopulated = input.all_unpopulated();
+        let generic_params = input.generic_params();
+        let target_where_block = input.target_where_block();
+        let new_impl_type = input.builder_ty(&input.all_unpopulated());
 
         let build_fields_init = input.field_idents().into_iter().map(|id| {
             quote! { #id: std::option::Option::None }
@@ -157,14 +161,9 @@ impl Transformer<TransformerParams, TokenStream> for NewImpl
 
         let fields_init = build_fields_init.chain(phantom_fields_init);
 
-        let new_impl_type = {
-            quote! {
-            #builder<#(#all_unpopulated),*>}
-        };
-
         let new_fn = quote! {
             #visibility fn new()
-                    -> #builder<#(#all_unpopulated),*>
+                    -> #new_impl_type
                 {
                     #builder {
                         #(#fields_init),*
@@ -173,7 +172,8 @@ impl Transformer<TransformerParams, TokenStream> for NewImpl
         };
 
         quote! {
-            impl #new_impl_type
+            impl<#(#generic_params),*> #new_impl_type
+                #target_where_block
             {
                 #new_fn
             }
@@ -190,23 +190,45 @@ impl Transformer<TransformerParams, TokenStream> for TargetImpl
     ) -> TokenStream
     {
         let visibility = input.visibility().clone();
-        let all_unpopulated = input.all_unpopulated();
         let builder = input.builder();
-        let target = input.target();
+        let builder_ty = input.builder_ty(&input.all_unpopulated());
+        let builder_fn = input.target_builder_fn();
+        let target_ty = input.target_ty();
+        let generic_params = input.generic_params();
+        let target_where_block = input.target_where_block();
 
         let target_new_builder_fn = quote! {
-            #visibility fn builder()
-                    -> #builder<#(#all_unpopulated),*>
+            #visibility fn #builder_fn()
+                    -> #builder_ty
                 {
                     #builder::new()
                 }
         };
 
+        let buildable_impl = input.variant().is_none().then(|| {
+            quote! {
+                impl<#(#generic_params),*> roopes::patterns::builder::Buildable
+                    for #target_ty
+                    #target_where_block
+                {
+                    type Builder = #builder_ty;
+
+                    fn builder() -> #builder_ty
+                    {
+                        #builder::new()
+                    }
+                }
+            }
+        });
+
         quote! {
-            impl #target
+            impl<#(#generic_params),*> #target_ty
+                #target_where_block
             {
                 #target_new_builder_fn
             }
+
+            #buildable_impl
         }
     }
 }
@@ -219,18 +241,18 @@ impl Transformer<TransformerParams, TokenStream> for SettersImpl
         input: &TransformerParams,
     ) -> TokenStream
     {
+        let generic_params = input.generic_params();
         let field_generic_names = input.field_generic_names();
         let builder_generic_ty = input.builder_generic_ty();
         let poppable_where_block = input.poppable_where_block();
 
         let setter_fns = input
             .fields()
-            .clone()
-            .into_iter()
-            .map(|e| SetterFn.transform(&(&e, input)));
+            .iter()
+            .map(|e| SetterFn.transform(&(e, input)));
 
         quote! {
-                impl<#(#field_generic_names),*>
+                impl<#(#generic_params,)* #(#field_generic_names),*>
                     #builder_generic_ty
                     #poppable_where_block
                 {
@@ -241,28 +263,247 @@ impl Transformer<TransformerParams, TokenStream> for SettersImpl
 }
 
 pub(super) struct SetterFn;
-impl Transformer<(&Field, &TransformerParams), TokenStream> for SetterFn
+impl Transformer<(&FieldParams, &TransformerParams), TokenStream> for SetterFn
+{
+    fn transform(
+        &self,
+        (field, input): &(&FieldParams, &TransformerParams),
+    ) -> TokenStream
+    {
+        let id = field.ident();
+        let ty = field.setter_ty();
+
+        let setter = SetterMethod.transform(&(
+            field,
+            input,
+            SetterSignature {
+                name: field.setter().clone(),
+                generics: quote!(),
+                params: quote!(#id: #ty),
+                bounds: quote!(),
+                body: field.setter_conversion().unwrap_or_default(),
+            },
+        ));
+
+        let nested =
+            field.nested().then(|| NestedFn.transform(&(field, input)));
+        let each = field
+            .each()
+            .as_ref()
+            .map(|_| EachFn.transform(&(field, input)));
+
+        quote! {
+            #setter
+            #nested
+            #each
+        }
+    }
+}
+
+/// The parts of a method which stores a new
+/// value for a field.  The `body` leaves the new
+/// value in a local named as the field.
+pub(super) struct SetterSignature
+{
+    name: Ident,
+    generics: TokenStream,
+    params: TokenStream,
+    bounds: TokenStream,
+    body: TokenStream,
+}
+
+/// Generates a method storing a new value for a
+/// field, validating it if the field has a
+/// validator, and giving back the builder with
+/// the field marked as set.
+struct SetterMethod;
+impl
+    Transformer<
+        (&FieldParams, &TransformerParams, SetterSignature),
+        TokenStream,
+    > for SetterMethod
 {
     fn transform(
         &self,
-        (field, input): &(&Field, &TransformerParams),
+        (field, input, signature): &(
+            &FieldParams,
+            &TransformerParams,
+            SetterSignature,
+        ),
     ) -> TokenStream
     {
         let visibility = input.visibility().clone();
+        let SetterSignature {
+            name,
+            generics,
+            params,
+            bounds,
+            body,
+        } = signature;
+        let receiver = if field.is_required() {
+            quote!(self)
+        } else {
+            quote!(mut self)
+        };
+        let (return_ty, result) = SetterResult.transform(&(field, input));
+
+        let (return_ty, result) = match field.setter_validation() {
+            | Some(validation) => {
+                let error = input.error();
+
+                (
+                    quote!(std::result::Result<#return_ty, #error>),
+                    quote! {
+                        #validation
+                        std::result::Result::Ok(#result)
+                    },
+                )
+            }
+            | None => (return_ty, result),
+        };
+
+        quote! {
+            #visibility fn #name<#generics>(#receiver, #params) -> #return_ty
+                where #bounds
+            {
+                #body
+                #result
+            }
+        }
+    }
+}
+
+/// Generates a method configuring a
+/// `#[builder(nested)]` field through its own
+/// builder, e.g.: `with_inner(|b| b.set_x(1))`.
+struct NestedFn;
+impl Transformer<(&FieldParams, &TransformerParams), TokenStream> for NestedFn
+{
+    fn transform(
+        &self,
+        (field, input): &(&FieldParams, &TransformerParams),
+    ) -> TokenStream
+    {
+        let id = field.ident();
+        let ty = field.value_ty();
+        let buildable = quote!(roopes::patterns::builder::Buildable);
+        let configure = format_ident!("configure", span = Span::mixed_site());
+
+        SetterMethod.transform(&(
+            field,
+            input,
+            SetterSignature {
+                name: format_ident!("with_{id}"),
+                generics: quote!(NestedConfigure, NestedBuilder),
+                params: quote!(#configure: NestedConfigure),
+                bounds: quote! {
+                    NestedConfigure: std::ops::FnOnce(
+                        <#ty as #buildable>::Builder
+                    ) -> NestedBuilder,
+                    NestedBuilder: std::convert::Into<#ty>,
+                },
+                body: quote! {
+                    let #id: #ty = std::convert::Into::into(
+                        #configure(<#ty as #buildable>::builder())
+                    );
+                },
+            },
+        ))
+    }
+}
+
+/// Generates a method adding a single item to a
+/// `#[builder(each = "item")]` collection field.
+struct EachFn;
+impl Transformer<(&FieldParams, &TransformerParams), TokenStream> for EachFn
+{
+    fn transform(
+        &self,
+        (field, input): &(&FieldParams, &TransformerParams),
+    ) -> TokenStream
+    {
+        let id = field.ident();
+        let Some(each) = field.each() else {
+            return quote!();
+        };
+        let item_tys = each.items();
+        let item_ids: Vec<_> = ["key", "value"][2 - item_tys.len()..]
+            .iter()
+            .map(|name| format_ident!("{name}", span = Span::mixed_site()))
+            .collect();
+        let item = if item_ids.len() == 1 {
+            quote!(#(#item_ids)*)
+        } else {
+            quote!((#(#item_ids),*))
+        };
+
+        let current = if field.is_required() {
+            quote!(self.#id)
+        } else {
+            quote!(self.#id.take())
+        };
+        let current = match field.default() {
+            | Some(default) => quote!(#current.unwrap_or_else(|| #default)),
+            | None => quote!(#current.unwrap_or_default()),
+        };
+
+        SetterMethod.transform(&(
+            field,
+            input,
+            SetterSignature {
+                name: each.name().clone(),
+                generics: quote!(),
+                params: quote!(#(#item_ids: #item_tys),*),
+                bounds: quote!(),
+                body: quote! {
+                    let mut #id = #current;
+                    std::iter::Extend::extend(
+                        &mut #id,
+                        std::iter::once(#item),
+                    );
+                },
+            },
+        ))
+    }
+}
+
+/// Gives a setter's return type and the
+/// expression giving the builder with the
+/// field's converted value stored.
+struct SetterResult;
+impl Transformer<(&FieldParams, &TransformerParams), (TokenStream, TokenStream)>
+    for SetterResult
+{
+    fn transform(
+        &self,
+        (field, input): &(&FieldParams, &TransformerParams),
+    ) -> (TokenStream, TokenStream)
+    {
+        let id = field.ident().clone();
+
+        if !field.is_required() {
+            return (
+                quote!(Self),
+                quote! {{
+                    self.#id = Some(#id);
+                    self
+                }},
+            );
+        }
+
         let field_idents = input.field_idents();
-        let id = field.ident.clone().unwrap();
-        let ty = field.ty.clone();
-        let fn_id = format_ident!("set_{id}");
         let builder = input.builder();
+        let generic_args = input.generic_args();
         let phantom_fields: Vec<_> = input.phantom_fields();
 
-        let return_generics = field_idents
-            .iter()
+        let return_generics = input
+            .required_idents()
+            .into_iter()
             .map(|gen_fld| {
                 if gen_fld.eq(&id) {
-                    TransformerParams::field_populated_struct_name(&id)
+                    input.field_populated_struct_name(&id)
                 } else {
-                    TransformerParams::field_generic_name(gen_fld)
+                    TransformerParams::field_generic_name(&gen_fld)
                 }
             })
             .map(|f| quote! {#f});
@@ -270,29 +511,26 @@ impl Transformer<(&Field, &TransformerParams), TokenStream> for SetterFn
         let remainder_ids = field_idents
             .iter()
             .filter(|fid| fid.ne(&&id))
-            .cloned()
             .map(|fid| {
                 quote! {
                     #fid: self.#fid
                 }
             })
-            .chain(phantom_fields.iter().cloned().map(|phantom_fid: Ident| {
+            .chain(phantom_fields.iter().map(|phantom_fid: &Ident| {
                 quote! {
                     #phantom_fid: std::marker::PhantomData {}
                 }
             }));
 
-        quote! {
-            #visibility fn
-                #fn_id(self, #id: #ty)
-                -> #builder<#(#return_generics),*>
-            {
+        (
+            quote!(#builder<#(#generic_args,)* #(#return_generics),*>),
+            quote! {
                 #builder {
                     #id: Some(#id),
                     #(#remainder_ids),*
                 }
-            }
-        }
+            },
+        )
     }
 }
 
@@ -304,22 +542,69 @@ impl Transformer<TransformerParams, TokenStream> for BuildImpl
         input: &TransformerParams,
     ) -> TokenStream
     {
-        let builder = input.builder();
-        let all_populated_generics = input.all_populated();
+        let builder_ty = input.builder_ty(&input.all_populated());
+        let generic_params = input.generic_params();
+        let target_where_block = input.target_where_block();
         let visibility = input.visibility().clone();
-        let target = input.target();
-        let build_set_fields = input.field_idents().into_iter().map(|id| {
-            quote! { #id: self.#id.unwrap() }
+        let target = input.target_path();
+        let target_ty = input.target_ty();
+        let build_set_fields = input.fields().iter().map(|field| {
+            let member = field.member();
+            let value = field.build_value();
+
+            quote! { #member: #value }
         });
 
-        quote! {
-            impl #builder<#(#all_populated_generics),*> {
-                #visibility fn build(self) -> #target {
-                    #target {
-                        #(#build_set_fields),*
+        let build_fn = match input.validate() {
+            | Some(validate) => {
+                let error = input.error();
+
+                quote! {
+                    #visibility fn try_build(self)
+                        -> std::result::Result<#target_ty, #error>
+                    {
+                        let target = #target {
+                            #(#build_set_fields),*
+                        };
+                        #validate(&target)?;
+
+                        std::result::Result::Ok(target)
+                    }
+                }
+            }
+            | None => {
+                quote! {
+                    #visibility fn build(self) -> #target_ty {
+                        #target {
+                            #(#build_set_fields),*
+                        }
                     }
                 }
             }
+        };
+
+        let from_impl = input.validate().is_none().then(|| {
+            quote! {
+                impl<#(#generic_params),*> std::convert::From<#builder_ty>
+                    for #target_ty
+                    #target_where_block
+                {
+                    fn from(builder: #builder_ty) -> Self
+                    {
+                        builder.build()
+                    }
+                }
+            }
+        });
+
+        quote! {
+            impl<#(#generic_params),*> #builder_ty
+                #target_where_block
+            {
+                #build_fn
+            }
+
+            #from_impl
         }
     }
 }
@@ -0,0 +1,44 @@
+use roopes::prelude::*;
+
+fn default_retries() -> u8 {
+    3
+}
+
+#[derive(Builder)]
+struct TestStruct {
+    #[builder(into)]
+    name: String,
+    #[builder(default)]
+    count: i32,
+    #[builder(default = default_retries())]
+    retries: u8,
+    #[builder(optional)]
+    limit: Option<usize>,
+    #[builder(optional, into)]
+    label: Option<String>,
+}
+
+fn main(){
+    let test_struct = TestStructBuilder::new()
+        .set_limit(5)
+        .set_name("first")
+        .build();
+
+    assert_eq!("first", test_struct.name);
+    assert_eq!(0, test_struct.count);
+    assert_eq!(3, test_struct.retries);
+    assert_eq!(Some(5), test_struct.limit);
+    assert_eq!(None, test_struct.label);
+
+    let test_struct = TestStruct::builder()
+        .set_count(1)
+        .set_retries(0)
+        .set_label("second")
+        .set_name(String::from("second"))
+        .build();
+
+    assert_eq!(1, test_struct.count);
+    assert_eq!(0, test_struct.retries);
+    assert_eq!(None, test_struct.limit);
+    assert_eq!(Some("second".to_string()), test_struct.label);
+}
@@ -0,0 +1,14 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct TestStruct {
+    required: i32,
+    #[builder(default)]
+    defaulted: i32,
+}
+
+fn main(){
+    let test_struct = TestStructBuilder::new().set_defaulted(1).build();
+
+    assert_eq!(1, test_struct.defaulted);
+}
@@ -0,0 +1,10 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct Request
+{
+    #[builder(each = "part")]
+    body: String,
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+use roopes::prelude::*;
+use std::collections::{
+    BTreeMap,
+    HashSet,
+};
+
+#[derive(Builder)]
+struct Request
+{
+    #[builder(each = "header")]
+    headers: BTreeMap<String, String>,
+    #[builder(each = "arg", default = vec!["-v".to_string()])]
+    args: Vec<String>,
+    #[builder(each = "tag", optional)]
+    tags: Option<HashSet<u8>>,
+}
+
+fn main()
+{
+    let request = Request::builder()
+        .arg("-x".to_string())
+        .header("Accept".to_string(), "*/*".to_string())
+        .header("Host".to_string(), "localhost".to_string())
+        .build();
+
+    assert_eq!(2, request.headers.len());
+    assert_eq!(vec!["-v", "-x"], request.args);
+    assert_eq!(None, request.tags);
+
+    let request = Request::builder()
+        .set_headers(BTreeMap::new())
+        .set_args(Vec::new())
+        .tag(1)
+        .tag(1)
+        .build();
+
+    assert!(request.headers.is_empty());
+    assert!(request.args.is_empty());
+    assert_eq!(Some(HashSet::from([1])), request.tags);
+}
@@ -0,0 +1,15 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct Request
+{
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "header")]
+    headers: Vec<String>,
+}
+
+fn main()
+{
+    let _ = Request::builder().arg("-x".to_string()).build();
+}
@@ -0,0 +1,38 @@
+use roopes::prelude::*;
+
+#[derive(Debug, PartialEq, Builder)]
+enum Shape
+{
+    Circle
+    {
+        radius: f64,
+    },
+    Rectangle
+    {
+        width: f64,
+        #[builder(default = 1.0)]
+        height: f64,
+    },
+    Polygon(#[builder(name = sides)] u8, f64),
+    Empty,
+}
+
+fn main()
+{
+    assert_eq!(
+        Shape::Circle { radius: 2.0 },
+        Shape::circle_builder().set_radius(2.0).build()
+    );
+    assert_eq!(
+        Shape::Rectangle {
+            width: 3.0,
+            height: 1.0
+        },
+        ShapeRectangleBuilder::new().set_width(3.0).build()
+    );
+    assert_eq!(
+        Shape::Polygon(6, 1.5),
+        Shape::polygon_builder().set_1(1.5).set_sides(6).build()
+    );
+    assert_ne!(Shape::Empty, Shape::circle_builder().set_radius(0.0).build());
+}
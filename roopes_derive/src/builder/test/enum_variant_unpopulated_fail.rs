@@ -0,0 +1,13 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+enum Shape
+{
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+fn main()
+{
+    let _ = Shape::square_builder().build();
+}
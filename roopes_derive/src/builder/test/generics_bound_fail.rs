@@ -0,0 +1,13 @@
+use roopes::prelude::*;
+
+struct NotClone;
+
+#[derive(Builder)]
+struct Wrapper<T: Clone>
+{
+    value: T,
+}
+
+fn unbuildable(_: WrapperBuilder<NotClone, FieldValueUnset>) {}
+
+fn main() {}
@@ -0,0 +1,40 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct Logger<P: Handler<String>>
+{
+    handler: P,
+    #[builder(default)]
+    prefix: String,
+}
+
+#[derive(Builder)]
+struct Pair<A, B = u8>
+where
+    A: Clone,
+{
+    first: A,
+    second: B,
+}
+
+#[derive(Builder)]
+struct Buffer<T: Default, const N: usize>
+{
+    items: [T; N],
+}
+
+fn main()
+{
+    let logger = Logger::builder()
+        .set_handler(handler::Lambda::new(|message: &String| {
+            println!("{message}");
+        }))
+        .build();
+    logger.handler.handle(&logger.prefix);
+
+    let pair: Pair<i32> = PairBuilder::new().set_second(2).set_first(1).build();
+    assert_eq!((1, 2), (pair.first, pair.second));
+
+    let buffer = Buffer::<u8, 2>::builder().set_items([1, 2]).build();
+    assert_eq!([1, 2], buffer.items);
+}
@@ -0,0 +1,16 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct Wrapper<'a, T: Clone>
+{
+    value: &'a T,
+    count: usize,
+}
+
+fn main()
+{
+    let value = 1;
+    let wrapper = WrapperBuilder::new().set_value(&value).build();
+
+    assert_eq!(0, wrapper.count);
+}
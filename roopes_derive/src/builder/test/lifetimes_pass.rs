@@ -0,0 +1,27 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct View<'a, 'b: 'a, T: ?Sized>
+where
+    T: std::fmt::Debug,
+{
+    name: &'a str,
+    value: &'b T,
+    #[builder(optional)]
+    parent: Option<&'a str>,
+}
+
+fn main()
+{
+    let name = String::from("view");
+    let value = vec![1, 2, 3];
+
+    let view = ViewBuilder::new()
+        .set_value(value.as_slice())
+        .set_name(&name)
+        .build();
+
+    assert_eq!("view", view.name);
+    assert_eq!(&[1, 2, 3], view.value);
+    assert_eq!(None, view.parent);
+}
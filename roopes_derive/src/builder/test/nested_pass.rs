@@ -0,0 +1,39 @@
+use roopes::prelude::*;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Inner
+{
+    x: i32,
+    #[builder(default)]
+    y: i32,
+}
+
+#[derive(Builder)]
+struct Outer
+{
+    #[builder(nested)]
+    inner: Inner,
+    #[builder(nested, optional)]
+    spare: Option<Inner>,
+    name: String,
+}
+
+fn main()
+{
+    let outer = Outer::builder()
+        .with_inner(|b| b.set_x(1))
+        .set_name("outer".to_string())
+        .build();
+
+    assert_eq!(Inner { x: 1, y: 0 }, outer.inner);
+    assert_eq!(None, outer.spare);
+
+    let outer = Outer::builder()
+        .set_name("again".to_string())
+        .set_inner(Inner { x: 2, y: 2 })
+        .with_spare(|b| b.set_y(3).set_x(4))
+        .build();
+
+    assert_eq!(Inner { x: 2, y: 2 }, outer.inner);
+    assert_eq!(Some(Inner { x: 4, y: 3 }), outer.spare);
+}
@@ -0,0 +1,19 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct Inner
+{
+    x: i32,
+}
+
+#[derive(Builder)]
+struct Outer
+{
+    #[builder(nested)]
+    inner: Inner,
+}
+
+fn main()
+{
+    let _ = Outer::builder().with_inner(|b| b).build();
+}
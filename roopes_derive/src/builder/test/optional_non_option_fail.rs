@@ -0,0 +1,9 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct TestStruct {
+    #[builder(optional)]
+    value: i32,
+}
+
+fn main(){}
@@ -0,0 +1,21 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct Point(i32, i32);
+
+#[derive(Builder)]
+struct Size(#[builder(name = width)] u32, #[builder(name = height, default = 1)] u32);
+
+#[derive(Builder)]
+struct Marker;
+
+fn main()
+{
+    let point = Point::builder().set_1(2).set_0(1).build();
+    assert_eq!((1, 2), (point.0, point.1));
+
+    let size = SizeBuilder::new().set_width(3).build();
+    assert_eq!((3, 1), (size.0, size.1));
+
+    let Marker = Marker::builder().build();
+}
@@ -0,0 +1,9 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+struct Point(i32, i32);
+
+fn main()
+{
+    let _ = Point::builder().set_0(1).build();
+}
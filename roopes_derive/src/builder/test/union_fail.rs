@@ -0,0 +1,10 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+union Bits
+{
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
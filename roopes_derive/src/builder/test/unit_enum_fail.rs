@@ -0,0 +1,10 @@
+use roopes::prelude::*;
+
+#[derive(Builder)]
+enum Direction
+{
+    Up,
+    Down,
+}
+
+fn main() {}
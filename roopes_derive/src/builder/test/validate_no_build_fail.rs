@@ -0,0 +1,19 @@
+use roopes::prelude::*;
+
+fn positive(target: &Positive) -> Result<(), String> {
+    if target.value > 0 {
+        Ok(())
+    } else {
+        Err("not positive".to_string())
+    }
+}
+
+#[derive(Builder)]
+#[builder(validate = positive)]
+struct Positive {
+    value: i32,
+}
+
+fn main() {
+    let _ = Positive::builder().set_value(0).build();
+}
@@ -0,0 +1,87 @@
+use roopes::prelude::*;
+
+#[derive(Debug, PartialEq)]
+enum RangeError {
+    Empty,
+    Inverted,
+    TooWide(u32),
+}
+
+fn non_empty(name: &String) -> Result<(), RangeError> {
+    if name.is_empty() {
+        Err(RangeError::Empty)
+    } else {
+        Ok(())
+    }
+}
+
+fn narrow(width: &u32) -> Result<(), RangeError> {
+    if *width > 100 {
+        Err(RangeError::TooWide(*width))
+    } else {
+        Ok(())
+    }
+}
+
+fn ordered(range: &Range) -> Result<(), RangeError> {
+    if range.low > range.high {
+        Err(RangeError::Inverted)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(validate = ordered, error = RangeError)]
+struct Range {
+    #[builder(into, validate = non_empty)]
+    name: String,
+    low: u32,
+    high: u32,
+    #[builder(default = 10, validate = narrow)]
+    width: u32,
+}
+
+fn unsigned(value: &i64) -> Result<(), String> {
+    (*value >= 0).then_some(()).ok_or(format!("{value} is negative"))
+}
+
+#[derive(Builder)]
+struct Counter {
+    #[builder(validate = unsigned)]
+    start: i64,
+}
+
+fn main() {
+    let range = Range::builder()
+        .set_name("valid")
+        .unwrap()
+        .set_low(1)
+        .set_high(2)
+        .try_build()
+        .unwrap();
+    assert_eq!((1, 2, 10), (range.low, range.high, range.width));
+
+    let inverted = Range::builder()
+        .set_name("inverted")
+        .unwrap()
+        .set_low(2)
+        .set_high(1)
+        .try_build();
+    assert_eq!(RangeError::Inverted, inverted.unwrap_err());
+
+    assert_eq!(
+        RangeError::Empty,
+        Range::builder().set_name("").map(|_| ()).unwrap_err()
+    );
+    assert_eq!(
+        RangeError::TooWide(200),
+        Range::builder().set_width(200).map(|_| ()).unwrap_err()
+    );
+
+    assert_eq!(3, Counter::builder().set_start(3).unwrap().build().start);
+    assert_eq!(
+        "-1 is negative",
+        Counter::builder().set_start(-1).map(|_| ()).unwrap_err()
+    );
+}
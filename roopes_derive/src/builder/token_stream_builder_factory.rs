@@ -7,7 +7,11 @@ use super::{
         SettersImpl,
         TargetImpl,
     },
-    transformer_params::TransformerParams,
+    transformer_params::{
+        EachParams,
+        FieldParams,
+        TransformerParams,
+    },
 };
 use crate::common::VecTokenStringTransformer;
 use proc_macro::TokenStream as TokenStream1;
@@ -16,7 +20,24 @@ use quote::format_ident;
 use roopes_core::prelude::*;
 use syn::{
     parse_macro_input,
+    parse_quote,
+    Attribute,
+    Data,
+    DataEnum,
+    DataStruct,
+    DataUnion,
     DeriveInput,
+    Error,
+    Expr,
+    Field,
+    Fields,
+    GenericArgument,
+    Ident,
+    LitStr,
+    Member,
+    Path,
+    PathArguments,
+    Type,
 };
 
 pub(super) struct TokenStreamBuilderFactory
@@ -37,11 +58,16 @@ impl TokenStreamBuilderFactory
         let tokens = self.token_stream.clone();
         let ast = parse_macro_input!(tokens as syn::DeriveInput);
 
-        let result: TokenStream2 = transformer_chain::heap::Head::new(
-            Box::new(TokenStreamToTransformerParamsTransformer),
-        )
-        .push(BuilderTransformer)
-        .transform(&ast);
+        let result: TokenStream2 = TokenStreamToTransformerParamsTransformer
+            .try_transform(&ast)
+            .map_or_else(Error::into_compile_error, |builders| {
+                let elements = builders
+                    .iter()
+                    .map(|params| BuilderTransformer.transform(params))
+                    .collect();
+
+                VecTokenStringTransformer.transform(&elements)
+            });
 
         // eprintln!("{}", result.to_string());
         // eprintln!("Formatted Results:");
@@ -77,42 +103,277 @@ impl Transformer<TransformerParams, TokenStream2> for BuilderTransformer
     }
 }
 
+/// Gives the parameters of each builder to
+/// generate: one for a struct, or one for each
+/// of an enum's variants with fields.
 pub(super) struct TokenStreamToTransformerParamsTransformer;
-impl Transformer<DeriveInput, TransformerParams>
+impl TryTransformer<DeriveInput, Vec<TransformerParams>>
     for TokenStreamToTransformerParamsTransformer
 {
-    fn transform(
+    type Error = Error;
+
+    fn try_transform(
         &self,
         input: &DeriveInput,
-    ) -> TransformerParams
+    ) -> Result<Vec<TransformerParams>, Error>
     {
         let ast = input.clone();
-        let visibility = ast.vis.clone();
         let target = ast.ident.clone();
-        let builder = format_ident!("{target}Builder");
-
-        let syn::DeriveInput {
-            data:
-                syn::Data::Struct(syn::DataStruct {
-                    fields:
-                        syn::Fields::Named(syn::FieldsNamed {
-                            named: fields, ..
-                        }),
-                    ..
-                }),
-            ..
-        } = ast
-        else {
-            unimplemented!(
-                "derive(Builder) only supports structs with named fields"
-            )
+
+        let (mut validate, mut error) = (None, None);
+        for attr in builder_attrs(&ast.attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("validate") {
+                    validate = Some(meta.value()?.parse::<Path>()?);
+                } else if meta.path.is_ident("error") {
+                    error = Some(meta.value()?.parse::<Type>()?);
+                } else {
+                    return Err(meta
+                        .error("expected `validate = fn` or `error = Type`"));
+                }
+                Ok(())
+            })?;
+        }
+
+        let params = |builder, variant, indicator_prefix, fields: &Fields| {
+            Ok(TransformerParams {
+                visibility: ast.vis.clone(),
+                target: target.clone(),
+                builder,
+                variant,
+                indicator_prefix,
+                generics: ast.generics.clone(),
+                fields: fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| parse_field(index, field))
+                    .collect::<Result<_, Error>>()?,
+                validate: validate.clone(),
+                error: error
+                    .clone()
+                    .unwrap_or_else(|| parse_quote!(std::string::String)),
+            })
         };
 
-        TransformerParams {
-            visibility,
-            target,
-            builder,
-            fields,
+        match &ast.data {
+            | Data::Struct(DataStruct { fields, .. }) => {
+                let indicator_prefix = match fields {
+                    | Fields::Unnamed(_) => target.to_string(),
+                    | _ => String::new(),
+                };
+
+                Ok(vec![params(
+                    format_ident!("{target}Builder"),
+                    None,
+                    indicator_prefix,
+                    fields,
+                )?])
+            }
+            | Data::Enum(DataEnum { variants, .. }) => {
+                let builders = variants
+                    .iter()
+                    .filter(|variant| !matches!(variant.fields, Fields::Unit))
+                    .map(|variant| {
+                        let name = &variant.ident;
+
+                        params(
+                            format_ident!("{target}{name}Builder"),
+                            Some(name.clone()),
+                            format!("{target}{name}"),
+                            &variant.fields,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                if builders.is_empty() {
+                    return Err(Error::new_spanned(
+                        &target,
+                        "derive(Builder) requires an enum to have a variant \
+                         with fields",
+                    ));
+                }
+
+                Ok(builders)
+            }
+            | Data::Union(DataUnion { union_token, .. }) => {
+                Err(Error::new_spanned(
+                    union_token,
+                    "derive(Builder) does not support unions",
+                ))
+            }
+        }
+    }
+}
+
+/// Parses a field and its `#[builder(..)]`
+/// attributes.  Positional fields are named by
+/// their index, e.g.: `set_0`, unless given
+/// `#[builder(name = ident)]`.
+fn parse_field(
+    index: usize,
+    field: &Field,
+) -> Result<FieldParams, Error>
+{
+    let (mut default, mut optional, mut into, mut validate, mut name) =
+        (None, false, false, None, None);
+    let (mut nested, mut each) = (false, None);
+
+    for attr in builder_attrs(&field.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                default = Some(if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse::<Expr>()?
+                } else {
+                    parse_quote!(std::default::Default::default())
+                });
+            } else if meta.path.is_ident("optional") {
+                optional = true;
+            } else if meta.path.is_ident("into") {
+                into = true;
+            } else if meta.path.is_ident("validate") {
+                validate = Some(meta.value()?.parse::<Path>()?);
+            } else if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<Ident>()?);
+            } else if meta.path.is_ident("nested") {
+                nested = true;
+            } else if meta.path.is_ident("each") {
+                each = Some(meta.value()?.parse::<LitStr>()?.parse::<Ident>()?);
+            } else {
+                return Err(meta.error(
+                    "expected `default`, `default = expr`, `optional`, \
+                     `into`, `validate = fn`, `name = ident`, `nested` or \
+                     `each = \"name\"`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    let (ident, member, setter) = match (name, &field.ident) {
+        | (Some(name), ident) => {
+            let member = ident
+                .clone()
+                .map_or_else(|| Member::Unnamed(index.into()), Member::Named);
+            let setter = format_ident!("set_{name}");
+            (name, member, setter)
+        }
+        | (None, Some(ident)) => {
+            let setter = format_ident!("set_{ident}");
+            (ident.clone(), Member::Named(ident.clone()), setter)
+        }
+        | (None, None) => {
+            (
+                format_ident!("_{index}"),
+                Member::Unnamed(index.into()),
+                format_ident!("set_{index}"),
+            )
+        }
+    };
+
+    if default.is_some() && optional {
+        return Err(Error::new_spanned(
+            &field.ty,
+            "a field cannot be both `default` and `optional`",
+        ));
+    }
+
+    let optional = if optional {
+        Some(option_inner_ty(&field.ty).ok_or_else(|| {
+            Error::new_spanned(
+                &field.ty,
+                "`#[builder(optional)]` requires an `Option<T>` field",
+            )
+        })?)
+    } else {
+        None
+    };
+
+    let each = each
+        .map(|name| {
+            let collection = optional.as_ref().unwrap_or(&field.ty);
+            let items = collection_item_tys(collection).ok_or_else(|| {
+                Error::new_spanned(
+                    collection,
+                    "`#[builder(each = \"..\")]` requires a `Vec`, \
+                     `VecDeque`, `HashSet`, `BTreeSet`, `HashMap` or \
+                     `BTreeMap` field",
+                )
+            })?;
+
+            Ok::<_, Error>(EachParams { name, items })
+        })
+        .transpose()?;
+
+    Ok(FieldParams {
+        ident,
+        member,
+        setter,
+        ty: field.ty.clone(),
+        default,
+        optional,
+        into,
+        validate,
+        nested,
+        each,
+    })
+}
+
+fn builder_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute>
+{
+    attrs.iter().filter(|attr| attr.path().is_ident("builder"))
+}
+
+/// Gives `T`, given `Option<T>`.
+fn option_inner_ty(ty: &Type) -> Option<Type>
+{
+    let (name, mut args) = generic_ty_args(ty)?;
+
+    (name == "Option" && args.len() == 1).then(|| args.remove(0))
+}
+
+/// Gives the types of the items added to a
+/// collection: `T`, given e.g.: `Vec<T>`, or `K`
+/// and `V`, given e.g.: `HashMap<K, V>`.
+fn collection_item_tys(ty: &Type) -> Option<Vec<Type>>
+{
+    let (name, mut args) = generic_ty_args(ty)?;
+
+    match name.to_string().as_str() {
+        | "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => {
+            args.truncate(1);
+            (args.len() == 1).then_some(args)
         }
+        | "HashMap" | "BTreeMap" => {
+            args.truncate(2);
+            (args.len() == 2).then_some(args)
+        }
+        | _ => None,
     }
 }
+
+/// Gives the name of a path type and its generic
+/// type arguments, e.g.: `Vec` and `T`, given
+/// `std::vec::Vec<T>`.
+fn generic_ty_args(ty: &Type) -> Option<(Ident, Vec<Type>)>
+{
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    let args = arguments
+        .args
+        .iter()
+        .filter_map(|arg| {
+            match arg {
+                | GenericArgument::Type(arg) => Some(arg.clone()),
+                | _ => None,
+            }
+        })
+        .collect();
+
+    Some((segment.ident.clone(), args))
+}
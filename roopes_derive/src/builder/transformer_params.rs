@@ -1,6 +1,7 @@
 use derive_getters::Getters;
 use heck::{
     ToShoutySnakeCase,
+    ToSnakeCase,
     ToUpperCamelCase,
 };
 use proc_macro2::{
@@ -10,11 +11,15 @@ use proc_macro2::{
 use quote::{
     format_ident,
     quote,
+    ToTokens,
 };
 use syn::{
-    punctuated::Punctuated,
-    token::Comma,
-    Field,
+    Expr,
+    GenericParam,
+    Generics,
+    Member,
+    Path,
+    Type,
     Visibility,
 };
 
@@ -24,57 +29,311 @@ pub(super) struct TransformerParams
     pub(super) visibility: Visibility,
     pub(super) target: Ident,
     pub(super) builder: Ident,
-    pub(super) fields: Punctuated<Field, Comma>,
+    pub(super) variant: Option<Ident>,
+    pub(super) indicator_prefix: String,
+    pub(super) generics: Generics,
+    pub(super) fields: Vec<FieldParams>,
+    pub(super) validate: Option<Path>,
+    pub(super) error: Type,
+}
+
+/// A field of the target struct, along with the
+/// options given by its `#[builder(..)]`
+/// attributes.
+#[derive(Clone, Getters)]
+pub(super) struct FieldParams
+{
+    pub(super) ident: Ident,
+    pub(super) member: Member,
+    pub(super) setter: Ident,
+    pub(super) ty: Type,
+    pub(super) default: Option<Expr>,
+    pub(super) optional: Option<Type>,
+    pub(super) into: bool,
+    pub(super) validate: Option<Path>,
+    pub(super) nested: bool,
+    pub(super) each: Option<EachParams>,
+}
+
+/// The method generated by
+/// `#[builder(each = "name")]`, adding one item
+/// to a collection field.
+#[derive(Clone, Getters)]
+pub(super) struct EachParams
+{
+    pub(super) name: Ident,
+    /// The item's type, or the key and value
+    /// types of a map.
+    pub(super) items: Vec<Type>,
+}
+
+impl FieldParams
+{
+    /// Indicates whether the field must be set
+    /// before the builder can build, i.e.: it has
+    /// neither a default nor is optional.
+    pub(super) fn is_required(&self) -> bool
+    {
+        self.default.is_none() && self.optional.is_none()
+    }
+
+    /// The type of the builder's field holding
+    /// this field's value until it's built.
+    pub(super) fn storage_ty(&self) -> TokenStream
+    {
+        let ty = self.ty();
+
+        if self.optional.is_some() {
+            quote!(#ty)
+        } else {
+            quote!(std::option::Option<#ty>)
+        }
+    }
+
+    /// The type the setter stores: the field's
+    /// type, or its inner type when optional.
+    pub(super) fn value_ty(&self) -> &Type
+    {
+        self.optional.as_ref().unwrap_or(&self.ty)
+    }
+
+    /// The setter's parameter type.
+    pub(super) fn setter_ty(&self) -> TokenStream
+    {
+        let ty = self.value_ty();
+
+        if self.into {
+            quote!(impl std::convert::Into<#ty>)
+        } else {
+            quote!(#ty)
+        }
+    }
+
+    /// Converts the setter's parameter, named as
+    /// the field, to the stored value's type, if
+    /// necessary.
+    pub(super) fn setter_conversion(&self) -> Option<TokenStream>
+    {
+        let id = self.ident();
+
+        self.into
+            .then(|| quote!(let #id = std::convert::Into::into(#id);))
+    }
+
+    /// Checks the setter's converted parameter
+    /// with the field's validator, if any,
+    /// returning early with its error.
+    pub(super) fn setter_validation(&self) -> Option<TokenStream>
+    {
+        let id = self.ident();
+
+        self.validate
+            .as_ref()
+            .map(|validate| quote!(#validate(&#id)?;))
+    }
+
+    /// Gives the field's final value from the
+    /// builder, `self`.
+    pub(super) fn build_value(&self) -> TokenStream
+    {
+        let id = self.ident();
+
+        match (&self.default, &self.optional) {
+            | (Some(default), _) => {
+                quote!(self.#id.unwrap_or_else(|| #default))
+            }
+            | (None, Some(_)) => quote!(self.#id),
+            | (None, None) => quote!(self.#id.unwrap()),
+        }
+    }
 }
 
 impl TransformerParams
 {
     pub(super) fn phantom_fields(&self) -> Vec<Ident>
     {
-        self.field_idents()
+        self.required_idents()
             .iter()
             .map(TransformerParams::phantom_field_name)
             .collect()
     }
 
     pub(super) fn builder_generic_ty(&self) -> TokenStream
+    {
+        self.builder_ty(&self.field_generic_names())
+    }
+
+    /// The builder's type, with the target's
+    /// generic arguments followed by the given
+    /// field indicators.
+    pub(super) fn builder_ty<I>(
+        &self,
+        indicators: &[I],
+    ) -> TokenStream
+    where
+        I: ToTokens,
     {
         let builder = self.builder();
-        let poppable_indicator_generics = self
-            .field_idents()
-            .into_iter()
-            .map(|fi| TransformerParams::field_generic_name(&fi));
+        let target_args = self.generic_args();
 
         quote! {
-            #builder<#(#poppable_indicator_generics),*>
+            #builder<#(#target_args,)* #(#indicators),*>
         }
     }
 
+    /// The path constructing the target: the
+    /// struct itself, or the enum's variant.
+    pub(super) fn target_path(&self) -> TokenStream
+    {
+        let target = self.target();
+
+        match self.variant() {
+            | Some(variant) => quote!(#target::#variant),
+            | None => quote!(#target),
+        }
+    }
+
+    /// The target's function giving a new
+    /// builder: `builder`, or, for an enum's
+    /// variant, e.g.: `circle_builder`.
+    pub(super) fn target_builder_fn(&self) -> Ident
+    {
+        match self.variant() {
+            | Some(variant) => {
+                let variant = variant.to_string().to_snake_case();
+                format_ident!("{variant}_builder")
+            }
+            | None => format_ident!("builder"),
+        }
+    }
+
+    /// The target's type, with its generic
+    /// arguments.
+    pub(super) fn target_ty(&self) -> TokenStream
+    {
+        let target = self.target();
+        let target_args = self.generic_args();
+
+        quote! {
+            #target<#(#target_args),*>
+        }
+    }
+
+    /// The target's generic parameters, as
+    /// declared on an `impl` or the builder, with
+    /// their bounds moved to
+    /// [`TransformerParams::generic_predicates`]
+    /// and their defaults dropped.
+    pub(super) fn generic_params(&self) -> Vec<TokenStream>
+    {
+        self.generics()
+            .params
+            .iter()
+            .map(|param| {
+                match param {
+                    | GenericParam::Lifetime(param) => {
+                        param.lifetime.to_token_stream()
+                    }
+                    | GenericParam::Type(param) => {
+                        param.ident.to_token_stream()
+                    }
+                    | GenericParam::Const(param) => {
+                        let (ident, ty) = (&param.ident, &param.ty);
+                        quote!(const #ident: #ty)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The target's generic parameters, as
+    /// arguments to its type.
+    pub(super) fn generic_args(&self) -> Vec<TokenStream>
+    {
+        self.generics()
+            .params
+            .iter()
+            .map(|param| {
+                match param {
+                    | GenericParam::Lifetime(param) => {
+                        param.lifetime.to_token_stream()
+                    }
+                    | GenericParam::Type(param) => {
+                        param.ident.to_token_stream()
+                    }
+                    | GenericParam::Const(param) => {
+                        param.ident.to_token_stream()
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The bounds of the target's generic
+    /// parameters, followed by its where-clause.
+    pub(super) fn generic_predicates(&self) -> Vec<TokenStream>
+    {
+        let param_bounds = self.generics().params.iter().filter_map(|param| {
+            match param {
+                | GenericParam::Lifetime(param) if !param.bounds.is_empty() => {
+                    let (lifetime, bounds) = (&param.lifetime, &param.bounds);
+                    Some(quote!(#lifetime: #bounds))
+                }
+                | GenericParam::Type(param) if !param.bounds.is_empty() => {
+                    let (ident, bounds) = (&param.ident, &param.bounds);
+                    Some(quote!(#ident: #bounds))
+                }
+                | _ => None,
+            }
+        });
+
+        let where_clause = self
+            .generics()
+            .where_clause
+            .iter()
+            .flat_map(|clause| &clause.predicates)
+            .map(ToTokens::to_token_stream);
+
+        param_bounds.chain(where_clause).collect()
+    }
+
+    pub(super) fn target_where_block(&self) -> TokenStream
+    {
+        let predicates = self.generic_predicates();
+
+        quote!(where #(#predicates),*)
+    }
+
     pub(super) fn all_unpopulated(&self) -> Vec<Ident>
     {
-        self.field_idents()
+        self.required_idents()
             .iter()
-            .map(Self::field_unpopulated_struct_name)
+            .map(|id| self.field_unpopulated_struct_name(id))
             .collect()
     }
 
     pub(super) fn all_populated(&self) -> Vec<Ident>
     {
-        self.field_idents()
+        self.required_idents()
             .iter()
-            .map(Self::field_populated_struct_name)
+            .map(|id| self.field_populated_struct_name(id))
             .collect()
     }
 
     pub(super) fn field_idents(&self) -> Vec<Ident>
+    {
+        self.fields().iter().map(|f| f.ident().clone()).collect()
+    }
+
+    /// The fields which must be set before the
+    /// builder can build, each of which is
+    /// tracked by a typestate generic.
+    pub(super) fn required_idents(&self) -> Vec<Ident>
     {
         self.fields()
-            .into_iter()
-            .map(|f| {
-                f.ident.clone().expect(
-                    "Builder only works on structs with fields with names.",
-                )
-            })
+            .iter()
+            .filter(|f| f.is_required())
+            .map(|f| f.ident().clone())
             .collect()
     }
 
@@ -89,21 +348,31 @@ impl TransformerParams
         format_ident!("{field_name}_set")
     }
 
-    pub(super) fn field_struct_base_name(field_name: &Ident) -> Ident
+    pub(super) fn field_struct_base_name(
+        &self,
+        field_name: &Ident,
+    ) -> Ident
     {
+        let prefix = self.indicator_prefix();
         let gn = field_name.to_string().to_upper_camel_case();
-        format_ident!("{gn}")
+        format_ident!("{prefix}{gn}")
     }
 
-    pub(super) fn field_populated_struct_name(field_name: &Ident) -> Ident
+    pub(super) fn field_populated_struct_name(
+        &self,
+        field_name: &Ident,
+    ) -> Ident
     {
-        let gn = Self::field_struct_base_name(field_name);
+        let gn = self.field_struct_base_name(field_name);
         format_ident!("Field{gn}Set")
     }
 
-    pub(super) fn field_unpopulated_struct_name(field_name: &Ident) -> Ident
+    pub(super) fn field_unpopulated_struct_name(
+        &self,
+        field_name: &Ident,
+    ) -> Ident
     {
-        let gn = Self::field_struct_base_name(field_name);
+        let gn = self.field_struct_base_name(field_name);
         format_ident!("Field{gn}Unset")
     }
 
@@ -127,7 +396,7 @@ impl TransformerParams
 
     pub(super) fn field_generic_names(&self) -> Vec<Ident>
     {
-        self.field_idents()
+        self.required_idents()
             .iter()
             .map(TransformerParams::field_generic_name)
             .collect()
@@ -137,10 +406,11 @@ impl TransformerParams
     {
         let builder_poppable = self.builder_poppable();
 
-        let where_clauses = self
-            .field_generic_names()
-            .into_iter()
-            .map(|pop_generic| quote!(#pop_generic : #builder_poppable));
+        let where_clauses = self.generic_predicates().into_iter().chain(
+            self.field_generic_names()
+                .into_iter()
+                .map(|pop_generic| quote!(#pop_generic : #builder_poppable)),
+        );
 
         quote!(where #(#where_clauses),*)
     }
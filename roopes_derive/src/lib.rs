@@ -1,11 +1,9 @@
 //! This crate implements macros supporting some
 //! patterns:
 //! - [`Builder`]
+//! - [`StateMachine`]
 //! - [`Visitor`]
 
-#![feature(proc_macro_internals)]
-#![feature(trait_alias)]
-#![feature(associated_type_bounds)]
 #![deny(
     clippy::pedantic,
     clippy::style,
@@ -18,6 +16,7 @@ use proc_macro::TokenStream;
 mod builder;
 mod common;
 mod publisher_subscriber;
+mod state_machine;
 mod visitor;
 
 /// Creates a new type on the specified `struct`,
@@ -25,6 +24,47 @@ mod visitor;
 /// The new builder type's name is the specified
 /// type, appended with "Builder".
 ///
+/// `build` is only available once every field has
+/// been set, except those marked with
+/// `#[builder(..)]` as:
+/// - `default`: built with [`Default::default`] when not set.
+/// - `default = expr`: built with `expr` when not set.
+/// - `optional`: an `Option<T>` field, whose setter takes a `T`, built as
+///   `None` when not set.
+///
+/// Fields marked `into` have setters accepting
+/// `impl Into<T>`, and those marked
+/// `validate = path` have setters which check
+/// the value with `path(&T) -> Result<(), E>`,
+/// giving back a `Result` of the builder.
+///
+/// Fields marked `nested`, whose type also
+/// derives `Builder`, get a method configuring
+/// them through that builder, e.g.:
+/// `.with_inner(|b| b.set_x(1))`.  Collection
+/// fields marked `each = "item"` get a method
+/// adding one item, or one key and value, at a
+/// time, which marks the field as set.
+///
+/// `#[builder(validate = path)]` on the specified
+/// type replaces `build` with `try_build`, which
+/// checks the built value with
+/// `path(&Target) -> Result<(), E>`.  The error
+/// type `E` is [`String`], unless given with
+/// `#[builder(error = Type)]`.
+///
+/// The builder carries the specified type's
+/// generic parameters, lifetimes and where-clause.
+///
+/// Tuple structs get positional setters, e.g.:
+/// `set_0`, unless the field is given
+/// `#[builder(name = ident)]`.  Enums get a
+/// builder for each variant with fields, named as
+/// the type and variant appended with "Builder",
+/// which is also given by e.g.:
+/// `Shape::circle_builder()`.  Unions are not
+/// supported.
+///
 /// # Examples
 /// ``` rust
 /// #[macro_use]
@@ -41,8 +81,28 @@ mod visitor;
 /// let test_obj = builder.build();
 ///
 /// assert_eq!(test_obj.field, 10);
+///
+/// #[derive(Builder)]
+/// struct Config
+/// {
+///     #[builder(into)]
+///     name: String,
+///     #[builder(default = 8080)]
+///     port: u16,
+///     #[builder(default)]
+///     verbose: bool,
+///     #[builder(optional, into)]
+///     motd: Option<String>,
+/// }
+///
+/// let config = ConfigBuilder::new().set_name("server").build();
+///
+/// assert_eq!(config.name, "server");
+/// assert_eq!(config.port, 8080);
+/// assert!(!config.verbose);
+/// assert_eq!(config.motd, None);
 /// ```
-#[proc_macro_derive(Builder)]
+#[proc_macro_derive(Builder, attributes(builder))]
 pub fn derive_builder(input: TokenStream) -> TokenStream
 {
     builder::derive(input)
@@ -102,6 +162,64 @@ pub fn derive_pubsub(input: TokenStream) -> TokenStream
     publisher_subscriber::derive(input)
 }
 
+/// Creates a state machine from the specified
+/// `enum` of unit variants, each a state, and the
+/// `#[transition(on = Event, to = Variant)]`
+/// attributes on them, which may also name a
+/// `guard = fn(&Event) -> bool`.  Generates a
+/// `state_machine()` function building the
+/// transition table as a
+/// `roopes::patterns::state::StateMachine`, and
+/// a context type, named as the specified type
+/// appended with "Context", which holds the
+/// running machine.
+///
+/// The event type is inferred from the `on`
+/// expressions, or may be given with
+/// `#[state_machine(event = Type)]`.  The first
+/// variant is the initial state, unless
+/// `#[state_machine(initial = Variant)]` says
+/// otherwise.  Targets which aren't variants, and
+/// variants unreachable from the initial state,
+/// are compile errors.
+///
+/// # Examples
+/// ``` rust
+/// #[macro_use]
+/// use roopes::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Coin
+/// {
+///     Insert,
+///     Push,
+/// }
+///
+/// fn paid(_: &Coin) -> bool
+/// {
+///     true
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Hash, StateMachine)]
+/// enum Turnstile
+/// {
+///     #[transition(on = Coin::Insert, to = Unlocked, guard = paid)]
+///     Locked,
+///     #[transition(on = Coin::Push, to = Locked)]
+///     Unlocked,
+/// }
+///
+/// let mut turnstile = TurnstileContext::new();
+/// assert!(!turnstile.handle(&Coin::Push));
+/// assert!(turnstile.handle(&Coin::Insert));
+/// assert_eq!(&Turnstile::Unlocked, turnstile.state());
+/// ```
+#[proc_macro_derive(StateMachine, attributes(state_machine, transition))]
+pub fn derive_state_machine(input: TokenStream) -> TokenStream
+{
+    state_machine::derive(input)
+}
+
 #[test]
 fn macro_tests()
 {
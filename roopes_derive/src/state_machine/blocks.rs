@@ -0,0 +1,184 @@
+use super::transformer_params::{
+    TransformerParams,
+    TransitionParams,
+};
+use crate::common::VecTokenStringTransformer;
+use proc_macro2::TokenStream;
+use quote::quote;
+use roopes_core::prelude::*;
+
+pub(super) struct TransitionTableTransformer;
+impl Transformer<TransformerParams, TokenStream> for TransitionTableTransformer
+{
+    fn transform(
+        &self,
+        input: &TransformerParams,
+    ) -> TokenStream
+    {
+        let visibility = input.visibility().clone();
+        let target = input.target().clone();
+        let initial = input.initial().clone();
+        let machine_type = input.machine_type();
+
+        let transitions = input.transitions().iter().map(|transition| {
+            TransitionTransformer.transform(&(transition, input))
+        });
+
+        quote! {
+            impl #target {
+                #visibility fn state_machine() -> #machine_type
+                {
+                    let mut machine =
+                        roopes::patterns::state::StateMachine::new(
+                            #target::#initial
+                        );
+                    #(#transitions)*
+                    machine
+                }
+            }
+        }
+    }
+}
+
+struct TransitionTransformer;
+impl Transformer<(&TransitionParams, &TransformerParams), TokenStream>
+    for TransitionTransformer
+{
+    fn transform(
+        &self,
+        (transition, input): &(&TransitionParams, &TransformerParams),
+    ) -> TokenStream
+    {
+        let target = input.target().clone();
+        let from = transition.from().clone();
+        let on = transition.on().clone();
+        let to = transition.to().clone();
+
+        match transition.guard() {
+            | Some(guard) => {
+                quote! {
+                    machine.guarded_transition(
+                        #target::#from,
+                        #on,
+                        #target::#to,
+                        #guard,
+                    );
+                }
+            }
+            | None => {
+                quote! {
+                    machine.transition(#target::#from, #on, #target::#to);
+                }
+            }
+        }
+    }
+}
+
+pub(super) struct ContextTransformer;
+impl Transformer<TransformerParams, TokenStream> for ContextTransformer
+{
+    fn transform(
+        &self,
+        input: &TransformerParams,
+    ) -> TokenStream
+    {
+        let elements = vec![
+            ContextStructTransformer.transform(input),
+            ContextImplTransformer.transform(input),
+            ContextDefaultTransformer.transform(input),
+        ];
+
+        VecTokenStringTransformer.transform(&elements)
+    }
+}
+
+struct ContextStructTransformer;
+impl Transformer<TransformerParams, TokenStream> for ContextStructTransformer
+{
+    fn transform(
+        &self,
+        input: &TransformerParams,
+    ) -> TokenStream
+    {
+        let visibility = input.visibility().clone();
+        let context = input.context().clone();
+        let machine_type = input.machine_type();
+
+        quote! {
+            #visibility struct #context
+            {
+                machine: #machine_type
+            }
+        }
+    }
+}
+
+struct ContextImplTransformer;
+impl Transformer<TransformerParams, TokenStream> for ContextImplTransformer
+{
+    fn transform(
+        &self,
+        input: &TransformerParams,
+    ) -> TokenStream
+    {
+        let visibility = input.visibility().clone();
+        let context = input.context().clone();
+        let target = input.target().clone();
+        let event = input.event().clone();
+        let machine_type = input.machine_type();
+
+        quote! {
+            impl #context
+            {
+                #visibility fn new() -> #context
+                {
+                    #context {
+                        machine: #target::state_machine()
+                    }
+                }
+
+                #visibility fn handle(&mut self, event: &#event) -> bool
+                {
+                    self.machine.handle(event)
+                }
+
+                #visibility fn state(&self) -> &#target
+                {
+                    self.machine.state()
+                }
+
+                #visibility fn machine(&self) -> &#machine_type
+                {
+                    &self.machine
+                }
+
+                #visibility fn machine_mut(&mut self) -> &mut #machine_type
+                {
+                    &mut self.machine
+                }
+            }
+        }
+    }
+}
+
+struct ContextDefaultTransformer;
+impl Transformer<TransformerParams, TokenStream> for ContextDefaultTransformer
+{
+    fn transform(
+        &self,
+        input: &TransformerParams,
+    ) -> TokenStream
+    {
+        let context = input.context().clone();
+
+        quote! {
+            impl Default for #context
+            {
+                fn default() -> #context
+                {
+                    #context::new()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub mod blocks;
+pub mod token_stream_state_machine_factory;
+pub mod transformer_params;
+
+use proc_macro::TokenStream;
+use token_stream_state_machine_factory::TokenStreamStateMachineFactory;
+
+pub fn derive(input: TokenStream) -> TokenStream
+{
+    TokenStreamStateMachineFactory::new_from_token_stream(input).build()
+}
@@ -0,0 +1,15 @@
+use roopes::prelude::*;
+
+#[derive(PartialEq)]
+enum Event {
+    Go,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, StateMachine)]
+enum Light {
+    #[transition(on = Event::Go, to = Red)]
+    Red,
+    Flashing(u8),
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use roopes::prelude::*;
+
+#[derive(PartialEq)]
+enum Event {
+    Go,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, StateMachine)]
+enum Light {
+    #[transition(on = Event::Go, to = Blue)]
+    Red,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use roopes::prelude::*;
+
+#[derive(PartialEq)]
+enum Event {
+    Go,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, StateMachine)]
+enum Light {
+    #[transition(on = Event::Go, to = Green)]
+    Red,
+    #[transition(on = Event::Go, to = Red)]
+    Green,
+    Amber,
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+use roopes::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Event {
+    Start,
+    Fail(u32),
+    Reset,
+}
+
+fn recoverable(event: &Event) -> bool {
+    matches!(event, Event::Fail(code) if *code < 500)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, StateMachine)]
+#[state_machine(initial = Idle)]
+pub enum Job {
+    #[transition(on = Event::Reset, to = Idle)]
+    Failed,
+    #[transition(on = Event::Start, to = Running)]
+    Idle,
+    #[transition(on = Event::Fail(404), to = Idle, guard = recoverable)]
+    #[transition(on = Event::Fail(500), to = Failed)]
+    Running,
+}
+
+fn main() {
+    let mut job = JobContext::default();
+    assert_eq!(&Job::Idle, job.state());
+
+    assert!(job.handle(&Event::Start));
+    assert!(job.handle(&Event::Fail(404)));
+    assert_eq!(&Job::Idle, job.state());
+
+    assert!(job.handle(&Event::Start));
+    assert!(job.handle(&Event::Fail(500)));
+    assert!(!job.handle(&Event::Start));
+    assert_eq!(&Job::Failed, job.state());
+
+    assert_eq!(4, Job::state_machine().rules().len());
+}
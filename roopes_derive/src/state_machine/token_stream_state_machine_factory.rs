@@ -0,0 +1,275 @@
+use super::{
+    blocks::{
+        ContextTransformer,
+        TransitionTableTransformer,
+    },
+    transformer_params::{
+        TransformerParams,
+        TransitionParams,
+    },
+};
+use crate::common::VecTokenStringTransformer;
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use roopes_core::prelude::*;
+use std::collections::HashSet;
+use syn::{
+    parse_macro_input,
+    Attribute,
+    DataEnum,
+    DeriveInput,
+    Error,
+    Expr,
+    Fields,
+    Ident,
+    Path,
+    Type,
+};
+
+pub(super) struct TokenStreamStateMachineFactory
+{
+    token_stream: TokenStream1,
+}
+
+impl TokenStreamStateMachineFactory
+{
+    pub(super) fn new_from_token_stream(token_stream: TokenStream1) -> Self
+    {
+        Self { token_stream }
+    }
+
+    pub fn build(&self) -> TokenStream1
+    {
+        let tokens = self.token_stream.clone();
+        let ast = parse_macro_input!(tokens as syn::DeriveInput);
+
+        let result: TokenStream2 = TokenStreamToTransformerParamsTransformer
+            .try_transform(&ast)
+            .map_or_else(Error::into_compile_error, |params| {
+                StateMachineTransformer.transform(&params)
+            });
+
+        result.into()
+    }
+}
+
+struct StateMachineTransformer;
+impl Transformer<TransformerParams, TokenStream2> for StateMachineTransformer
+{
+    fn transform(
+        &self,
+        shared: &TransformerParams,
+    ) -> TokenStream2
+    {
+        let elements = vec![
+            TransitionTableTransformer.transform(shared),
+            ContextTransformer.transform(shared),
+        ];
+
+        VecTokenStringTransformer.transform(&elements)
+    }
+}
+
+pub(super) struct TokenStreamToTransformerParamsTransformer;
+impl TryTransformer<DeriveInput, TransformerParams>
+    for TokenStreamToTransformerParamsTransformer
+{
+    type Error = Error;
+
+    fn try_transform(
+        &self,
+        input: &DeriveInput,
+    ) -> Result<TransformerParams, Error>
+    {
+        let ast = input.clone();
+        let visibility = ast.vis.clone();
+        let target = ast.ident.clone();
+        let context = format_ident!("{target}Context");
+
+        let syn::Data::Enum(DataEnum { variants, .. }) = ast.data else {
+            return Err(Error::new_spanned(
+                &target,
+                "derive(StateMachine) only supports enums",
+            ));
+        };
+
+        let (mut event, mut initial) = (None, None);
+        for attr in attrs_named(&ast.attrs, "state_machine") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("event") {
+                    event = Some(meta.value()?.parse::<Type>()?);
+                } else if meta.path.is_ident("initial") {
+                    initial = Some(meta.value()?.parse::<Ident>()?);
+                } else {
+                    return Err(meta.error(
+                        "expected `event = Type` or `initial = Variant`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+
+        let mut states = Vec::new();
+        let mut transitions = Vec::new();
+        for variant in &variants {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(Error::new_spanned(
+                    &variant.fields,
+                    "derive(StateMachine) states must be unit variants",
+                ));
+            }
+            states.push(variant.ident.clone());
+
+            for attr in attrs_named(&variant.attrs, "transition") {
+                transitions.push(parse_transition(&variant.ident, attr)?);
+            }
+        }
+
+        let initial = match initial {
+            | Some(initial) => initial,
+            | None => {
+                states.first().cloned().ok_or_else(|| {
+                    Error::new_spanned(
+                        &target,
+                        "derive(StateMachine) requires at least one state",
+                    )
+                })?
+            }
+        };
+
+        for state in std::iter::once(&initial)
+            .chain(transitions.iter().map(TransitionParams::to))
+        {
+            if !states.contains(state) {
+                return Err(Error::new_spanned(
+                    state,
+                    format!("undefined target state `{state}`"),
+                ));
+            }
+        }
+
+        let reachable = reachable_states(&initial, &transitions);
+        if let Some(unreachable) =
+            states.iter().find(|state| !reachable.contains(state))
+        {
+            return Err(Error::new_spanned(
+                unreachable,
+                format!(
+                    "state `{unreachable}` is unreachable from initial state \
+                     `{initial}`"
+                ),
+            ));
+        }
+
+        let event = match event {
+            | Some(event) => event,
+            | None => {
+                infer_event_type(&transitions).ok_or_else(|| {
+                    Error::new_spanned(
+                        &target,
+                        "cannot infer the event type; add \
+                         `#[state_machine(event = Type)]`",
+                    )
+                })?
+            }
+        };
+
+        Ok(TransformerParams {
+            visibility,
+            target,
+            context,
+            event,
+            initial,
+            transitions,
+        })
+    }
+}
+
+fn attrs_named<'a>(
+    attrs: &'a [Attribute],
+    name: &'a str,
+) -> impl Iterator<Item = &'a Attribute>
+{
+    attrs.iter().filter(move |attr| attr.path().is_ident(name))
+}
+
+fn parse_transition(
+    from: &Ident,
+    attr: &Attribute,
+) -> Result<TransitionParams, Error>
+{
+    let (mut on, mut to, mut guard) = (None, None, None);
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("on") {
+            on = Some(meta.value()?.parse::<Expr>()?);
+        } else if meta.path.is_ident("to") {
+            to = Some(meta.value()?.parse::<Ident>()?);
+        } else if meta.path.is_ident("guard") {
+            guard = Some(meta.value()?.parse::<Path>()?);
+        } else {
+            return Err(meta.error(
+                "expected `on = Event`, `to = Variant` or `guard = fn`",
+            ));
+        }
+        Ok(())
+    })?;
+
+    let missing = |key| {
+        Error::new_spanned(attr, format!("transition is missing `{key} = ..`"))
+    };
+
+    Ok(TransitionParams {
+        from: from.clone(),
+        on: on.ok_or_else(|| missing("on"))?,
+        to: to.ok_or_else(|| missing("to"))?,
+        guard,
+    })
+}
+
+fn reachable_states<'a>(
+    initial: &'a Ident,
+    transitions: &'a [TransitionParams],
+) -> HashSet<&'a Ident>
+{
+    let mut reachable = HashSet::from([initial]);
+    let mut frontier = vec![initial];
+
+    while let Some(state) = frontier.pop() {
+        for transition in transitions.iter().filter(|t| t.from() == state) {
+            if reachable.insert(transition.to()) {
+                frontier.push(transition.to());
+            }
+        }
+    }
+
+    reachable
+}
+
+/// Takes the event type from the first `on`
+/// expression naming an enum variant, e.g.:
+/// `Event::Go` or `Event::Set(1)`.
+fn infer_event_type(transitions: &[TransitionParams]) -> Option<Type>
+{
+    transitions.iter().find_map(|transition| {
+        let path = match transition.on() {
+            | Expr::Path(expr) => &expr.path,
+            | Expr::Call(expr) => {
+                match &*expr.func {
+                    | Expr::Path(func) => &func.path,
+                    | _ => return None,
+                }
+            }
+            | Expr::Struct(expr) => &expr.path,
+            | _ => return None,
+        };
+
+        let mut path = path.clone();
+        path.segments.pop()?;
+        path.segments.pop_punct();
+
+        (!path.segments.is_empty())
+            .then(|| Type::Path(syn::TypePath { qself: None, path }))
+    })
+}
@@ -0,0 +1,45 @@
+use derive_getters::Getters;
+use proc_macro2::{
+    Ident,
+    TokenStream,
+};
+use quote::quote;
+use syn::{
+    Expr,
+    Path,
+    Type,
+    Visibility,
+};
+
+#[derive(Clone, Getters)]
+pub(super) struct TransformerParams
+{
+    pub(super) visibility: Visibility,
+    pub(super) target: Ident,
+    pub(super) context: Ident,
+    pub(super) event: Type,
+    pub(super) initial: Ident,
+    pub(super) transitions: Vec<TransitionParams>,
+}
+
+#[derive(Clone, Getters)]
+pub(super) struct TransitionParams
+{
+    pub(super) from: Ident,
+    pub(super) on: Expr,
+    pub(super) to: Ident,
+    pub(super) guard: Option<Path>,
+}
+
+impl TransformerParams
+{
+    pub(super) fn machine_type(&self) -> TokenStream
+    {
+        let target = self.target();
+        let event = self.event();
+
+        quote! {
+            roopes::patterns::state::StateMachine<#target, #event>
+        }
+    }
+}
@@ -24,7 +24,7 @@ fn main()
             // Pull out the prefix at lambda
             // build time: it does not change
             // after the lambda is built.
-            let prefix = (*greeting_message.borrow()).prefix;
+            let prefix = greeting_message.borrow().prefix;
 
             // Create local state for the
             // number of times the lambda is
@@ -54,18 +54,14 @@ fn main()
 
     // Change the prefix after the builder is
     // setup.
-    greeting_message.replace(GreetingMessage {
-        prefix: "BBB".into(),
-    });
+    greeting_message.replace(GreetingMessage { prefix: "BBB" });
 
     // Create two independent loggers.
     let bbb_logger = logger_factory.create();
 
     // Change the prefix after the builder is
     // setup.
-    greeting_message.replace(GreetingMessage {
-        prefix: "CCC".into(),
-    });
+    greeting_message.replace(GreetingMessage { prefix: "CCC" });
     let ccc_logger: ObservingCommand<_> = logger_factory.create();
 
     // Attach a logger to a minimal subject.
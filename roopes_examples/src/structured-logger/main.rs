@@ -1,4 +1,3 @@
-#![feature(trait_alias)]
 #![feature(unboxed_closures)]
 
 mod log_formatter;
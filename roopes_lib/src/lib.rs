@@ -45,6 +45,33 @@
 //! optimized builds. `dyn` should occur in the provided traits, but
 //! implementations often use it (e.g: `Box` or `Vec`).
 //!
+//! ## Thread Safety
+//! Most provided implementations are built on `Box`, `Rc` and `RefCell`, so
+//! they can't cross a thread boundary. Where types need to be shared between
+//! threads, `Sync`-prefixed counterparts are provided alongside them (e.g.:
+//! `command::SyncHeap`, `observer::SyncVecSubject` or
+//! `publisher_subscriber::SyncVecPublisher`), which are built on `Arc`,
+//! `Mutex` and `RwLock` and require their delegates to be `Send + Sync`.
+//!
+//! ## Asynchronous Use
+//! `Async`-prefixed counterparts of the central traits (e.g.: `AsyncHandler`,
+//! `AsyncCommand`, `AsyncTransformer` and `AsyncPublisher`) return boxed
+//! futures, so they can be used behind `dyn` without tying the library to any
+//! particular runtime. Their methods carry an `_async` suffix (e.g.:
+//! `handle_async`), so a type may implement both a trait and its counterpart
+//! without ambiguous calls. Each comes with an `AsyncHeap` (or
+//! `AsyncVecPublisher`) implementation, and an `AsyncAdapter` which wraps the
+//! corresponding synchronous trait. Where no runtime is at hand,
+//! `asynchronous::executor::block_on` drives a future on the current thread.
+//!
+//! ## Fallible Use
+//! `Try`-prefixed counterparts (`TryHandler`, `TryCommand`, `TryTransformer`
+//! and `TryPublisher`) declare an associated `Error` type and return `Result`s.
+//! `transformer_chain::try_heap` short-circuits at the first failing stage,
+//! converting stage errors with `From` just as `?` does, and `TryVecPublisher`
+//! either stops at the first failing subscriber or collects every error,
+//! depending on its `FailurePolicy`.
+//!
 //! ## Usage
 //! To install, add the
 //! crate to your `cargo.toml` as usual. The types provided are minimal, but the
@@ -96,6 +123,8 @@
 //!
 //! - [`roopes_core::patterns::abstract_factory`]
 //! Defines a method of creating typed objects.
+//! - [`roopes_core::patterns::builder::Buildable`]
+//! Names the builder a type is constructed through.
 //! - [`roopes_core::patterns::command::Command`]
 //! Encapsulates a block of executable code.
 //! - [`roopes_core::patterns::heap_pool::HeapPool`]
@@ -112,6 +141,8 @@
 //! Aids in the configuration and construction of similar objects.
 //! - [`roopes_derive::PubSub`]
 //! Simplified subscriber dispatch type generation on a type.
+//! - [`roopes_derive::StateMachine`]
+//! Declares a state machine's transitions on an enum of its states.
 //! - [`roopes_derive::Visitor`]
 //! Ensures a type can consume a message type.
 //!
@@ -186,8 +217,6 @@
 //! type-system. This library attempts compromise to leverage the patterns OOP
 //! gives us, to enable larger projects, but also have Rust's type-system and
 //! borrow checker on hand.
-#![feature(trait_alias)]
-#![feature(associated_type_bounds)]
 #![allow(unused_imports)]
 #[macro_use]
 extern crate roopes_derive;